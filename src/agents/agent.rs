@@ -1,27 +1,29 @@
 use rand::Rng;
 
 use crate::{
-    policies::Policy,
-    types::{Action, Reward, State},
-    values::StateActionValue,
+    spaces::Space,
+    types::{Action, Observation, Reward, Transition},
 };
 
-/// Definition of a generic agent.
+/// Definition of a generic agent, given its policy and its value function.
+///
+/// The policy and the value function are not constrained, so that value-based agents, e.g. with a
+/// [`Policy`](crate::policies::Policy) over a [`StateActionValue`](crate::values::StateActionValue),
+/// and policy-based agents, e.g. with a [`ParametricPolicy`](crate::policies::ParametricPolicy)
+/// and a [`StateValue`](crate::values::StateValue) baseline, share the same sessions.
 pub trait Agent<A, R, S, P, V>
 where
     A: Action,
     R: Reward,
-    S: State,
-    P: Policy,
-    V: StateActionValue<A, R, S>,
+    S: Observation,
 {
-    /// Iterates of the action space.
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a A> + 'a>;
+    /// Gets the action space.
+    fn action_space(&self) -> &dyn Space<A>;
 
-    /// Iterates of the state space.
-    fn states_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a S> + 'a>;
+    /// Gets the state space.
+    fn state_space(&self) -> &dyn Space<S>;
 
-    /// Constructs an agent given a policy and a (state-)action value function.
+    /// Constructs an agent given a policy and a value function.
     fn new(pi: P, v: V) -> Self;
 
     /// Computes the action for given state.
@@ -29,9 +31,19 @@ where
     where
         T: Rng + ?Sized;
 
+    /// Checks whether the agent is in evaluation mode.
+    fn is_eval(&self) -> bool;
+
+    /// Sets the evaluation mode, where the agent exploits its knowledge without exploring.
+    fn set_eval(&mut self, is_eval: bool) -> &mut Self;
+
     /// Resets the agent.
     fn reset(&mut self) -> &mut Self;
 
-    /// Updates the agent given performed action, obtained reward, next state and end-of-episode flag.
-    fn update(&mut self, action: &A, reward: &R, state: &S, is_done: bool);
+    /// Updates the agent given the transition, i.e. the state, performed action,
+    /// obtained reward, next state and end-of-episode flag, and the random number generator,
+    /// e.g. to sample past transitions from a replay memory.
+    fn update<T>(&mut self, transition: &Transition<A, R, S>, rng: &mut T)
+    where
+        T: Rng + ?Sized;
 }
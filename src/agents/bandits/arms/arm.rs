@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use rand::prelude::*;
 
-use crate::types::Reward;
+use crate::{types::Reward, Result};
 
 /// Definition of a generic bandit arm.
 pub trait Arm<R>: Clone + Debug + Default
@@ -16,7 +16,13 @@ where
     fn reset(&mut self);
 
     /// Sample from underlying distribution with given random number generator.
-    fn sample<T: Rng + ?Sized>(&self, rng: &mut T) -> R;
+    fn sample<T: Rng + ?Sized>(&self, rng: &mut T) -> R {
+        self.try_sample(rng).expect("Unable to sample from bandit's arm")
+    }
+
+    /// Sample from underlying distribution with given random number generator,
+    /// failing if the distribution parameters are invalid.
+    fn try_sample<T: Rng + ?Sized>(&self, rng: &mut T) -> Result<R>;
 
     /// Update the  underlying distribution parameters.
     fn update(&mut self, reward: &R);
@@ -2,9 +2,11 @@ use rand::prelude::*;
 use rand_distr::Beta;
 
 use super::Arm;
+use crate::{Error, Result};
 
 /// Bernoulli bandit arm.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bernoulli {
     init_alpha: f64,
     init_beta: f64,
@@ -14,15 +16,30 @@ pub struct Bernoulli {
 
 impl Bernoulli {
     /// Constructs a Bernoulli bandit arm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are not positive, see [`Bernoulli::try_new`].
     pub fn new(alpha: f64, beta: f64) -> Self {
-        // FIXME: Sanitize inputs.
+        Self::try_new(alpha, beta).expect("Unable to construct Bernoulli bandit arm")
+    }
 
-        Self {
+    /// Constructs a Bernoulli bandit arm, failing if the parameters are not positive.
+    pub fn try_new(alpha: f64, beta: f64) -> Result<Self> {
+        // Check the parameters of the Beta distribution.
+        if !(alpha > 0. && beta > 0. && alpha.is_finite() && beta.is_finite()) {
+            return Err(Error::InvalidParameter(format!(
+                "Bernoulli parameters must be positive, got alpha = {} and beta = {}",
+                alpha, beta
+            )));
+        }
+
+        Ok(Self {
             init_alpha: alpha,
             init_beta: beta,
             alpha,
             beta,
-        }
+        })
     }
 }
 
@@ -37,11 +54,16 @@ impl Arm<f64> for Bernoulli {
         self.beta = self.init_beta;
     }
 
-    fn sample<T: Rng + ?Sized>(&self, rng: &mut T) -> f64 {
+    fn try_sample<T: Rng + ?Sized>(&self, rng: &mut T) -> Result<f64> {
         // Sample from given distribution.
-        Beta::new(self.alpha, self.beta)
-            .expect("Unable to construct Beta distribution for given parameters")
-            .sample(rng)
+        let beta = Beta::new(self.alpha, self.beta).map_err(|e| {
+            Error::InvalidParameter(format!(
+                "Beta distribution with alpha = {} and beta = {}: {}",
+                self.alpha, self.beta, e
+            ))
+        })?;
+
+        Ok(beta.sample(rng))
     }
 
     #[allow(unused_parens)]
@@ -1,29 +1,34 @@
-use std::{collections::HashMap, marker::PhantomData};
+use std::{collections::HashMap, hash::Hash, marker::PhantomData};
 
 use rand::Rng;
 
 use super::arms::Arm;
 use crate::{
     agents::Agent,
-    policies::Policy,
-    types::{Action, Reward, State},
+    policies::{Greedy, Policy},
+    spaces::{Enumerated, Space},
+    types::{Action, Observation, Reward, Transition},
     values::{ActionValue, StateActionValue},
+    Error, Result,
 };
 
 /// Action value function of a MAB.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Arms<A, R, V>
 where
-    A: Action,
+    A: Action + Eq + Hash,
     R: Reward,
     V: Arm<R>,
 {
     _r_marker: PhantomData<R>,
+    actions: Enumerated<A>,
     arms: HashMap<A, V>,
 }
 
 impl<A, R, V> Arms<A, R, V>
 where
-    A: Action,
+    A: Action + Eq + Hash,
     R: Reward,
     V: Arm<R>,
 {
@@ -32,12 +37,7 @@ where
     where
         I: Iterator<Item = A>,
     {
-        let arms = actions_iter.map(|a| (a, Default::default())).collect();
-
-        Self {
-            _r_marker: PhantomData,
-            arms,
-        }
+        Self::from_actions_arms_iter(actions_iter.map(|a| (a, Default::default())))
     }
 
     /// Constructs a sequence of arms given the (action, arm) pairs.
@@ -45,10 +45,17 @@ where
     where
         I: Iterator<Item = (A, V)>,
     {
-        let arms = actions_arms_iter.collect();
+        // Keep the actions in insertion order, so that iterations are reproducible.
+        let (mut actions, mut arms) = (Enumerated::new([]), HashMap::new());
+        for (action, arm) in actions_arms_iter {
+            if arms.insert(action.clone(), arm).is_none() {
+                actions.push(action);
+            }
+        }
 
         Self {
             _r_marker: PhantomData,
+            actions,
             arms,
         }
     }
@@ -56,16 +63,20 @@ where
 
 impl<A, R, V> ActionValue<A, R> for Arms<A, R, V>
 where
-    A: Action,
+    A: Action + Eq + Hash,
     R: Reward,
     V: Arm<R>,
 {
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a A> + 'a> {
-        Box::new(self.arms.keys())
+    fn action_space(&self) -> &dyn Space<A> {
+        &self.actions
     }
 
-    fn call(&self, action: &A) -> R {
-        self.arms[action].call()
+    fn try_call(&self, action: &A) -> Result<R> {
+        // Compute the value of the arm associated with the given action.
+        self.arms
+            .get(action)
+            .map(|arm| arm.call())
+            .ok_or_else(|| Error::UnknownAction(format!("{:?}", action)))
     }
 
     fn reset(&mut self) -> &mut Self {
@@ -74,21 +85,25 @@ where
         self
     }
 
-    fn update(&mut self, action: &A, reward: &R) {
+    fn try_update(&mut self, action: &A, reward: &R) -> Result<()> {
         // Update the arm association with performed action given the obtained reward.
         self.arms
             .get_mut(action)
-            .expect("Unable to get bandit's arm for given action")
-            .update(reward)
+            .ok_or_else(|| Error::UnknownAction(format!("{:?}", action)))?
+            .update(reward);
+
+        Ok(())
     }
 }
 
 /// (Contextual) multi armed bandit agent (MAB).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MultiArmedBandit<A, R, S, P, V>
 where
     A: Action,
     R: Reward,
-    S: State,
+    S: Observation,
     P: Policy,
     V: StateActionValue<A, R, S>,
 {
@@ -97,22 +112,23 @@ where
     _s_marker: PhantomData<S>,
     pi: P,
     v: V,
+    is_eval: bool,
 }
 
 impl<A, R, S, P, V> Agent<A, R, S, P, V> for MultiArmedBandit<A, R, S, P, V>
 where
     A: Action,
     R: Reward,
-    S: State,
+    S: Observation,
     P: Policy,
     V: StateActionValue<A, R, S>,
 {
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a A> + 'a> {
-        self.v.actions_iter()
+    fn action_space(&self) -> &dyn Space<A> {
+        self.v.action_space()
     }
 
-    fn states_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a S> + 'a> {
-        self.v.states_iter()
+    fn state_space(&self) -> &dyn Space<S> {
+        self.v.state_space()
     }
 
     fn new(pi: P, v: V) -> Self
@@ -126,6 +142,7 @@ where
             _s_marker: PhantomData,
             pi,
             v,
+            is_eval: false,
         }
     }
 
@@ -133,8 +150,21 @@ where
     where
         T: Rng + ?Sized,
     {
-        // Evaluate the value function for each action.
-        self.pi.call(&self.v, state, rng)
+        // Evaluate the value function for each action, greedily if in evaluation mode.
+        match self.is_eval {
+            false => self.pi.call(&self.v, state, rng),
+            true => Greedy::default().call(&self.v, state, rng),
+        }
+    }
+
+    fn is_eval(&self) -> bool {
+        self.is_eval
+    }
+
+    fn set_eval(&mut self, is_eval: bool) -> &mut Self {
+        self.is_eval = is_eval;
+
+        self
     }
 
     fn reset(&mut self) -> &mut Self {
@@ -144,8 +174,13 @@ where
         self
     }
 
-    fn update(&mut self, action: &A, reward: &R, state: &S, _is_done: bool) {
-        // Update the (state-)action value function.
-        self.v.update(action, reward, state)
+    fn update<T>(&mut self, transition: &Transition<A, R, S>, _rng: &mut T)
+    where
+        T: Rng + ?Sized,
+    {
+        // Update the (state-)action value function given the state the action is performed in.
+        self.v.update(&transition.action, &transition.reward, &transition.state);
+        // Advance the policy, e.g. its exploration schedule.
+        self.pi.step();
     }
 }
@@ -0,0 +1,228 @@
+use rand::Rng;
+
+use super::Agent;
+use crate::{
+    networks::Optimizer,
+    policies::{Greedy, Policy},
+    replays::{Replay, Uniform},
+    spaces::Space,
+    types::{Action, Transition},
+    values::{Neural, StateActionValue},
+};
+
+/// Deep Q-network agent, as in Mnih et al. (2015).
+///
+/// The agent stores its transitions in a [`Uniform`] replay memory, then updates its neural value function
+/// on batches sampled from the memory with the given random number generator, towards the
+/// reward plus the discounted value of the best action in the next state, as estimated by a target
+/// network that is periodically synchronized with the value function. If double, as in van Hasselt
+/// et al. (2016), the best action is selected by the value function and evaluated by the target network.
+/// The exploration schedule, e.g. a decaying [`EpsilonGreedy`](crate::policies::EpsilonGreedy),
+/// is advanced by the policy at each update.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dqn<A, P, O>
+where
+    A: Action,
+    P: Policy,
+    O: Optimizer,
+{
+    pi: P,
+    v: Neural<A, O>,
+    target: Neural<A, O>,
+    gamma: f64,
+    memory: Uniform<A, f64, Vec<f64>>,
+    batch_size: usize,
+    period: usize,
+    count: usize,
+    is_double: bool,
+    is_eval: bool,
+}
+
+impl<A, P, O> Dqn<A, P, O>
+where
+    A: Action,
+    P: Policy,
+    O: Optimizer,
+{
+    /// Sets the discount factor of the returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the discount factor is not in `[0, 1]`.
+    pub fn with_discount(mut self, gamma: f64) -> Self {
+        assert!((0. ..=1.).contains(&gamma), "Discount factor must be in [0, 1]");
+        self.gamma = gamma;
+
+        self
+    }
+
+    /// Sets the capacity of the replay memory, where the oldest transitions are discarded first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is not positive.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.memory = Uniform::new(capacity);
+
+        self
+    }
+
+    /// Sets the size of the batches, where updates start once the memory holds a batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the batch size is not positive.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "Batch size must be positive");
+        self.batch_size = batch_size;
+
+        self
+    }
+
+    /// Sets the number of updates between synchronizations of the target network.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the period is not positive.
+    pub fn with_target_period(mut self, period: usize) -> Self {
+        assert!(period > 0, "Period must be positive");
+        self.period = period;
+
+        self
+    }
+
+    /// Sets whether the targets are computed as in double Q-learning.
+    pub fn with_double(mut self, is_double: bool) -> Self {
+        self.is_double = is_double;
+
+        self
+    }
+
+    /// Gets the policy of the agent.
+    pub fn policy(&self) -> &P {
+        &self.pi
+    }
+
+    /// Gets the value function of the agent.
+    pub fn value(&self) -> &Neural<A, O> {
+        &self.v
+    }
+
+    /// Computes the bootstrapped target of the given transition.
+    fn target(&self, transition: &Transition<A, f64, Vec<f64>>) -> f64 {
+        if transition.is_done {
+            return transition.reward;
+        }
+
+        let q = self.target.values(&transition.next_state);
+        let q = match self.is_double {
+            // Evaluate the best action of the value function with the target network ...
+            true => {
+                let (i, _) = self.v.values(&transition.next_state).into_iter().enumerate().fold(
+                    (0, f64::NEG_INFINITY),
+                    |(i, x), (j, y)| if y > x { (j, y) } else { (i, x) },
+                );
+                q[i]
+            }
+            // ... otherwise evaluate the best action of the target network.
+            false => q.into_iter().fold(f64::NEG_INFINITY, f64::max),
+        };
+
+        transition.reward + self.gamma * q
+    }
+}
+
+impl<A, P, O> Agent<A, f64, Vec<f64>, P, Neural<A, O>> for Dqn<A, P, O>
+where
+    A: Action,
+    P: Policy,
+    O: Optimizer,
+{
+    fn action_space(&self) -> &dyn Space<A> {
+        self.v.action_space()
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        self.v.state_space()
+    }
+
+    fn new(pi: P, v: Neural<A, O>) -> Self {
+        Self {
+            pi,
+            target: v.clone(),
+            v,
+            gamma: 0.99,
+            memory: Uniform::new(10_000),
+            batch_size: 32,
+            period: 500,
+            count: 0,
+            is_double: false,
+            is_eval: false,
+        }
+    }
+
+    fn call<T>(&self, state: &Vec<f64>, rng: &mut T) -> A
+    where
+        T: Rng + ?Sized,
+    {
+        // Evaluate the value function for each action, greedily if in evaluation mode.
+        match self.is_eval {
+            false => self.pi.call(&self.v, state, rng),
+            true => Greedy::default().call(&self.v, state, rng),
+        }
+    }
+
+    fn is_eval(&self) -> bool {
+        self.is_eval
+    }
+
+    fn set_eval(&mut self, is_eval: bool) -> &mut Self {
+        self.is_eval = is_eval;
+
+        self
+    }
+
+    fn reset(&mut self) -> &mut Self {
+        self.pi.reset();
+        self.v.reset();
+        self.target = self.v.clone();
+        self.memory.clear();
+        self.count = 0;
+
+        self
+    }
+
+    fn update<T>(&mut self, transition: &Transition<A, f64, Vec<f64>>, rng: &mut T)
+    where
+        T: Rng + ?Sized,
+    {
+        // Store the transition, overwriting the oldest one if the memory is full.
+        self.memory.push(transition.clone());
+        // Advance the policy, e.g. its exploration schedule.
+        self.pi.step();
+        // Wait until the memory holds a batch.
+        if self.memory.len() < self.batch_size {
+            return;
+        }
+        // Sample a batch uniformly with replacement and compute its targets.
+        let batch: Vec<_> = self
+            .memory
+            .sample(self.batch_size, rng)
+            .into_iter()
+            .map(|(_, transition, _)| {
+                (
+                    transition.action.clone(),
+                    self.target(transition),
+                    transition.state.clone(),
+                )
+            })
+            .collect();
+        self.v.update_batch(&batch);
+        // Synchronize the target network periodically, counting the updates since the last synchronization.
+        self.count = (self.count + 1) % self.period;
+        if self.count == 0 {
+            self.target = self.v.clone();
+        }
+    }
+}
@@ -3,3 +3,15 @@ pub use agent::Agent;
 
 /// Multi-armed bandits module.
 pub mod bandits;
+
+mod reinforce;
+pub use reinforce::Reinforce;
+
+mod sarsa;
+pub use sarsa::Sarsa;
+
+mod q_learning;
+pub use q_learning::QLearning;
+
+mod dqn;
+pub use dqn::Dqn;
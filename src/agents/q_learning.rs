@@ -0,0 +1,137 @@
+use std::marker::PhantomData;
+
+use rand::Rng;
+
+use super::Agent;
+use crate::{
+    policies::{Greedy, Policy},
+    spaces::Space,
+    types::{Action, Observation, Transition},
+    values::StateActionValue,
+};
+
+/// Semi-gradient Q-learning agent, as in Sutton & Barto (Section 6.5 and Section 10.1).
+///
+/// The agent updates the value of each action towards the reward plus the discounted value of the
+/// best action in the next state, regardless of the action performed, hence the action space must
+/// be enumerable.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QLearning<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: Policy,
+    V: StateActionValue<A, f64, S>,
+{
+    _a_marker: PhantomData<A>,
+    _s_marker: PhantomData<S>,
+    pi: P,
+    v: V,
+    gamma: f64,
+    is_eval: bool,
+}
+
+impl<A, S, P, V> QLearning<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: Policy,
+    V: StateActionValue<A, f64, S>,
+{
+    /// Sets the discount factor of the returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the discount factor is not in `[0, 1]`.
+    pub fn with_discount(mut self, gamma: f64) -> Self {
+        assert!((0. ..=1.).contains(&gamma), "Discount factor must be in [0, 1]");
+        self.gamma = gamma;
+
+        self
+    }
+
+    /// Gets the value function of the agent.
+    pub fn value(&self) -> &V {
+        &self.v
+    }
+}
+
+impl<A, S, P, V> Agent<A, f64, S, P, V> for QLearning<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: Policy,
+    V: StateActionValue<A, f64, S>,
+{
+    fn action_space(&self) -> &dyn Space<A> {
+        self.v.action_space()
+    }
+
+    fn state_space(&self) -> &dyn Space<S> {
+        self.v.state_space()
+    }
+
+    fn new(pi: P, v: V) -> Self {
+        Self {
+            _a_marker: PhantomData,
+            _s_marker: PhantomData,
+            pi,
+            v,
+            gamma: 1.,
+            is_eval: false,
+        }
+    }
+
+    fn call<T>(&self, state: &S, rng: &mut T) -> A
+    where
+        T: Rng + ?Sized,
+    {
+        // Evaluate the value function for each action, greedily if in evaluation mode.
+        match self.is_eval {
+            false => self.pi.call(&self.v, state, rng),
+            true => Greedy::default().call(&self.v, state, rng),
+        }
+    }
+
+    fn is_eval(&self) -> bool {
+        self.is_eval
+    }
+
+    fn set_eval(&mut self, is_eval: bool) -> &mut Self {
+        self.is_eval = is_eval;
+
+        self
+    }
+
+    fn reset(&mut self) -> &mut Self {
+        self.pi.reset();
+        self.v.reset();
+
+        self
+    }
+
+    fn update<T>(&mut self, transition: &Transition<A, f64, S>, _rng: &mut T)
+    where
+        T: Rng + ?Sized,
+    {
+        // Bootstrap from the best action in the next state, unless the episode is over.
+        let target = match transition.is_done {
+            false => {
+                let next_state = &transition.next_state;
+                let q = self
+                    .v
+                    .action_space()
+                    .iter()
+                    .expect("Unable to iterate of enumerable space")
+                    .map(|a| self.v.call(&a, next_state))
+                    .fold(f64::NEG_INFINITY, f64::max);
+                transition.reward + self.gamma * q
+            }
+            true => transition.reward,
+        };
+        self.v.update(&transition.action, &target, &transition.state);
+        // Advance the policy, e.g. its exploration schedule.
+        self.pi.step();
+    }
+}
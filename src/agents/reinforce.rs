@@ -0,0 +1,146 @@
+use rand::Rng;
+
+use super::Agent;
+use crate::{
+    policies::ParametricPolicy,
+    spaces::Space,
+    types::{Action, Observation, Transition},
+    values::StateValue,
+};
+
+/// REINFORCE agent with baseline, as in Sutton & Barto (Section 13.4).
+///
+/// The agent follows a parametric policy, e.g. a [`Gaussian`](crate::policies::Gaussian) policy
+/// over a continuous action space, and updates it at the end of each episode along the gradient
+/// of the log-probability of the performed actions, weighted by their returns minus the baseline.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reinforce<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: ParametricPolicy<A, S>,
+    V: StateValue<f64, S>,
+{
+    pi: P,
+    v: V,
+    alpha: f64,
+    gamma: f64,
+    episode: Vec<(S, A, f64)>,
+    is_eval: bool,
+}
+
+impl<A, S, P, V> Reinforce<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: ParametricPolicy<A, S>,
+    V: StateValue<f64, S>,
+{
+    /// Sets the step size of the policy updates.
+    pub fn with_step_size(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+
+        self
+    }
+
+    /// Sets the discount factor of the returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the discount factor is not in `[0, 1]`.
+    pub fn with_discount(mut self, gamma: f64) -> Self {
+        assert!((0. ..=1.).contains(&gamma), "Discount factor must be in [0, 1]");
+        self.gamma = gamma;
+
+        self
+    }
+
+    /// Gets the policy of the agent.
+    pub fn policy(&self) -> &P {
+        &self.pi
+    }
+}
+
+impl<A, S, P, V> Agent<A, f64, S, P, V> for Reinforce<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: ParametricPolicy<A, S>,
+    V: StateValue<f64, S>,
+{
+    fn action_space(&self) -> &dyn Space<A> {
+        self.pi.action_space()
+    }
+
+    fn state_space(&self) -> &dyn Space<S> {
+        self.pi.state_space()
+    }
+
+    fn new(pi: P, v: V) -> Self {
+        Self {
+            pi,
+            v,
+            alpha: 1e-3,
+            gamma: 1.,
+            episode: vec![],
+            is_eval: false,
+        }
+    }
+
+    fn call<T>(&self, state: &S, rng: &mut T) -> A
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the policy, or take its most likely action if in evaluation mode.
+        match self.is_eval {
+            false => self.pi.call(state, rng),
+            true => self.pi.mode(state),
+        }
+    }
+
+    fn is_eval(&self) -> bool {
+        self.is_eval
+    }
+
+    fn set_eval(&mut self, is_eval: bool) -> &mut Self {
+        self.is_eval = is_eval;
+
+        self
+    }
+
+    fn reset(&mut self) -> &mut Self {
+        self.pi.reset();
+        self.v.reset();
+        self.episode.clear();
+
+        self
+    }
+
+    fn update<T>(&mut self, transition: &Transition<A, f64, S>, _rng: &mut T)
+    where
+        T: Rng + ?Sized,
+    {
+        // Record the step until the end of the episode.
+        self.episode
+            .push((transition.state.clone(), transition.action.clone(), transition.reward));
+        if !transition.is_done {
+            return;
+        }
+        // Accumulate the gradient backwards, computing the return of each step.
+        let mut step = vec![0.; self.pi.parameters().len()];
+        let mut g = 0.;
+        for (t, (state, action, reward)) in self.episode.drain(..).enumerate().rev() {
+            g = reward + self.gamma * g;
+            // Weight the gradient by the return minus the baseline, then update the baseline.
+            let delta = g - self.v.call(&state);
+            self.v.update(&state, &g);
+            let weight = self.alpha * self.gamma.powi(t as i32) * delta;
+            for (s, x) in step.iter_mut().zip(self.pi.grad_log_prob(&action, &state)) {
+                *s += weight * x;
+            }
+        }
+        // Update the policy.
+        self.pi.update(&step);
+    }
+}
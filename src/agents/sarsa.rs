@@ -0,0 +1,127 @@
+use rand::Rng;
+
+use super::Agent;
+use crate::{
+    policies::{Greedy, Policy},
+    spaces::Space,
+    types::{Action, Observation, Transition},
+    values::StateActionValue,
+};
+
+/// Semi-gradient SARSA agent, as in Sutton & Barto (Section 10.1).
+///
+/// The agent updates the value of each action towards the reward plus the discounted value of the
+/// next action, which is known once the agent performs it, hence each update is deferred by one step.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sarsa<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: Policy,
+    V: StateActionValue<A, f64, S>,
+{
+    pi: P,
+    v: V,
+    gamma: f64,
+    previous: Option<Transition<A, f64, S>>,
+    is_eval: bool,
+}
+
+impl<A, S, P, V> Sarsa<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: Policy,
+    V: StateActionValue<A, f64, S>,
+{
+    /// Sets the discount factor of the returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the discount factor is not in `[0, 1]`.
+    pub fn with_discount(mut self, gamma: f64) -> Self {
+        assert!((0. ..=1.).contains(&gamma), "Discount factor must be in [0, 1]");
+        self.gamma = gamma;
+
+        self
+    }
+
+    /// Gets the value function of the agent.
+    pub fn value(&self) -> &V {
+        &self.v
+    }
+}
+
+impl<A, S, P, V> Agent<A, f64, S, P, V> for Sarsa<A, S, P, V>
+where
+    A: Action,
+    S: Observation,
+    P: Policy,
+    V: StateActionValue<A, f64, S>,
+{
+    fn action_space(&self) -> &dyn Space<A> {
+        self.v.action_space()
+    }
+
+    fn state_space(&self) -> &dyn Space<S> {
+        self.v.state_space()
+    }
+
+    fn new(pi: P, v: V) -> Self {
+        Self {
+            pi,
+            v,
+            gamma: 1.,
+            previous: None,
+            is_eval: false,
+        }
+    }
+
+    fn call<T>(&self, state: &S, rng: &mut T) -> A
+    where
+        T: Rng + ?Sized,
+    {
+        // Evaluate the value function for each action, greedily if in evaluation mode.
+        match self.is_eval {
+            false => self.pi.call(&self.v, state, rng),
+            true => Greedy::default().call(&self.v, state, rng),
+        }
+    }
+
+    fn is_eval(&self) -> bool {
+        self.is_eval
+    }
+
+    fn set_eval(&mut self, is_eval: bool) -> &mut Self {
+        self.is_eval = is_eval;
+
+        self
+    }
+
+    fn reset(&mut self) -> &mut Self {
+        self.pi.reset();
+        self.v.reset();
+        self.previous = None;
+
+        self
+    }
+
+    fn update<T>(&mut self, transition: &Transition<A, f64, S>, _rng: &mut T)
+    where
+        T: Rng + ?Sized,
+    {
+        // Update the previous action, given the action performed in its next state.
+        if let Some(previous) = self.previous.take() {
+            let target = previous.reward + self.gamma * self.v.call(&transition.action, &transition.state);
+            self.v.update(&previous.action, &target, &previous.state);
+        }
+        // Update the last action of the episode with its reward, otherwise defer the update.
+        match transition.is_done {
+            false => self.previous = Some(transition.clone()),
+            true => self.v.update(&transition.action, &transition.reward, &transition.state),
+        }
+        // Advance the policy, e.g. its exploration schedule.
+        self.pi.step();
+    }
+}
@@ -0,0 +1,183 @@
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+use super::{Discretizer, Env, Observable};
+use crate::spaces::{Bounded, Discrete, Space};
+
+/// Acrobot environment, as in Sutton & Barto (Section 11.3 of the first edition) and Gym's `Acrobot-v1`.
+///
+/// The continuous observation is the (first joint angle, second joint angle, first joint angular velocity,
+/// second joint angular velocity) tuple, while the state is its discretization. The actions apply a torque
+/// of -1, 0 and +1 to the second joint, each step is rewarded with -1 and the episode ends when the tip
+/// of the second link swings above the height of one link over the base.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Acrobot {
+    actions: Discrete,
+    discretizer: Discretizer,
+    observations: Bounded,
+    observation: [f64; 4],
+    count: usize,
+    end: usize,
+}
+
+impl Acrobot {
+    /// Gravitational acceleration.
+    const GRAVITY: f64 = 9.8;
+    /// Length of the first link.
+    const LINK_LENGTH_1: f64 = 1.;
+    /// Mass of the first link.
+    const LINK_MASS_1: f64 = 1.;
+    /// Mass of the second link.
+    const LINK_MASS_2: f64 = 1.;
+    /// Position of the center of mass of the first link.
+    const LINK_COM_1: f64 = 0.5;
+    /// Position of the center of mass of the second link.
+    const LINK_COM_2: f64 = 0.5;
+    /// Moment of inertia of both links.
+    const LINK_MOI: f64 = 1.;
+    /// Seconds between state updates.
+    const DT: f64 = 0.2;
+    /// Maximum absolute angular velocity of the first joint.
+    pub const MAX_VEL_1: f64 = 4. * PI;
+    /// Maximum absolute angular velocity of the second joint.
+    pub const MAX_VEL_2: f64 = 9. * PI;
+
+    /// Constructs an acrobot environment given the discretizer of the observations.
+    pub fn new(discretizer: Discretizer) -> Self {
+        Self {
+            actions: Discrete(3),
+            discretizer,
+            observations: Bounded::new(
+                vec![-PI, -PI, -Self::MAX_VEL_1, -Self::MAX_VEL_2],
+                vec![PI, PI, Self::MAX_VEL_1, Self::MAX_VEL_2],
+            ),
+            observation: [0.; 4],
+            count: 0,
+            end: 500,
+        }
+    }
+
+    /// Constructs the discretizer of the observations given the number of bins of each dimension.
+    pub fn discretizer(bins: [usize; 4]) -> Discretizer {
+        Discretizer::new(
+            vec![-PI, -PI, -Self::MAX_VEL_1, -Self::MAX_VEL_2],
+            vec![PI, PI, Self::MAX_VEL_1, Self::MAX_VEL_2],
+            bins.to_vec(),
+        )
+    }
+
+    /// Sets the maximum number of steps per episode.
+    pub fn with_horizon(mut self, end: usize) -> Self {
+        self.end = end;
+
+        self
+    }
+
+    /// Gets the continuous observation of the environment.
+    pub fn observation(&self) -> [f64; 4] {
+        self.observation
+    }
+
+    /// Computes the time derivative of the observation given the applied torque.
+    fn derivative(s: &[f64; 4], torque: f64) -> [f64; 4] {
+        let (m1, m2) = (Self::LINK_MASS_1, Self::LINK_MASS_2);
+        let (l1, lc1, lc2) = (Self::LINK_LENGTH_1, Self::LINK_COM_1, Self::LINK_COM_2);
+        let (i1, i2, g) = (Self::LINK_MOI, Self::LINK_MOI, Self::GRAVITY);
+        let [theta1, theta2, dtheta1, dtheta2] = *s;
+
+        let d1 = m1 * lc1.powi(2) + m2 * (l1.powi(2) + lc2.powi(2) + 2. * l1 * lc2 * theta2.cos()) + i1 + i2;
+        let d2 = m2 * (lc2.powi(2) + l1 * lc2 * theta2.cos()) + i2;
+        let phi2 = m2 * lc2 * g * (theta1 + theta2 - PI / 2.).cos();
+        let phi1 = -m2 * l1 * lc2 * dtheta2.powi(2) * theta2.sin()
+            - 2. * m2 * l1 * lc2 * dtheta2 * dtheta1 * theta2.sin()
+            + (m1 * lc1 + m2 * l1) * g * (theta1 - PI / 2.).cos()
+            + phi2;
+        let ddtheta2 = (torque + d2 / d1 * phi1 - m2 * l1 * lc2 * dtheta1.powi(2) * theta2.sin() - phi2)
+            / (m2 * lc2.powi(2) + i2 - d2.powi(2) / d1);
+        let ddtheta1 = -(d2 * ddtheta2 + phi1) / d1;
+
+        [dtheta1, dtheta2, ddtheta1, ddtheta2]
+    }
+}
+
+impl Default for Acrobot {
+    fn default() -> Self {
+        Self::new(Self::discretizer([6, 6, 6, 6]))
+    }
+}
+
+/// Wraps an angle into `[-pi, pi)`.
+fn wrap(x: f64) -> f64 {
+    (x + PI).rem_euclid(2. * PI) - PI
+}
+
+impl Observable for Acrobot {
+    fn observation_space(&self) -> &Bounded {
+        &self.observations
+    }
+
+    fn get_observation(&self) -> Vec<f64> {
+        self.observation.to_vec()
+    }
+}
+
+impl Env<usize, f64, Vec<usize>> for Acrobot {
+    fn action_space(&self) -> &dyn Space<usize> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<usize>> {
+        self.discretizer.space()
+    }
+
+    fn get_state(&self) -> Vec<usize> {
+        self.discretizer.call(&self.observation)
+    }
+
+    fn call_mut<T>(&mut self, action: &usize, _rng: &mut T) -> (f64, Vec<usize>, bool)
+    where
+        T: Rng + ?Sized,
+    {
+        let torque = *action as f64 - 1.;
+        // Integrate the dynamics with a fourth-order Runge-Kutta step.
+        let s = self.observation;
+        let step = |k: &[f64; 4], h: f64| [0, 1, 2, 3].map(|i| s[i] + h * k[i]);
+        let k1 = Self::derivative(&s, torque);
+        let k2 = Self::derivative(&step(&k1, Self::DT / 2.), torque);
+        let k3 = Self::derivative(&step(&k2, Self::DT / 2.), torque);
+        let k4 = Self::derivative(&step(&k3, Self::DT), torque);
+        let s = [0, 1, 2, 3].map(|i| s[i] + Self::DT / 6. * (k1[i] + 2. * k2[i] + 2. * k3[i] + k4[i]));
+        // Wrap the angles and bound the angular velocities.
+        self.observation = [
+            wrap(s[0]),
+            wrap(s[1]),
+            s[2].clamp(-Self::MAX_VEL_1, Self::MAX_VEL_1),
+            s[3].clamp(-Self::MAX_VEL_2, Self::MAX_VEL_2),
+        ];
+        // Increment counter.
+        self.count += 1;
+
+        let [theta1, theta2, _, _] = self.observation;
+        let is_terminal = -theta1.cos() - (theta1 + theta2).cos() > 1.;
+        let reward = match is_terminal {
+            false => -1.,
+            true => 0.,
+        };
+
+        (reward, self.get_state(), is_terminal || self.count >= self.end)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the initial observation close to the resting position.
+        self.observation = [(); 4].map(|_| rng.gen_range(-0.1..0.1));
+        // Reset the time step counter.
+        self.count = 0;
+
+        self
+    }
+}
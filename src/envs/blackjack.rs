@@ -0,0 +1,160 @@
+use rand::Rng;
+
+use super::Env;
+use crate::spaces::{Enumerated, Space};
+
+/// Action of a blackjack player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlackjackAction {
+    /// Stop requesting cards.
+    Stick,
+    /// Request an additional card.
+    Hit,
+}
+
+/// Hand of blackjack cards, i.e. the sum of the cards, counting aces as one, whether an ace is held
+/// and the number of cards.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Hand {
+    sum: usize,
+    ace: bool,
+    cards: usize,
+}
+
+impl Hand {
+    fn draw<T>(&mut self, rng: &mut T)
+    where
+        T: Rng + ?Sized,
+    {
+        // Draw from an infinite deck, where face cards count as ten.
+        let card = usize::min(rng.gen_range(1..=13), 10);
+        self.sum += card;
+        self.ace |= card == 1;
+        self.cards += 1;
+    }
+
+    fn usable_ace(&self) -> bool {
+        self.ace && self.sum + 10 <= 21
+    }
+
+    fn value(&self) -> usize {
+        match self.usable_ace() {
+            false => self.sum,
+            true => self.sum + 10,
+        }
+    }
+
+    fn natural(&self) -> bool {
+        // An ace and a ten-card, i.e. twenty-one with the first two cards.
+        self.cards == 2 && self.value() == 21
+    }
+}
+
+/// Blackjack environment, as in Sutton & Barto (Example 5.1).
+///
+/// The state is the (player sum, dealer showing card, usable ace) tuple, where cards are dealt
+/// from an infinite deck, the player automatically hits below twelve and the dealer sticks on
+/// seventeen or more. Rewards are +1, -1 and 0 for winning, losing and drawing, respectively.
+/// A natural, i.e. twenty-one with the first two cards, wins unless the dealer has a natural as well,
+/// in which case it is a draw, even if the dealer reaches twenty-one with more cards.
+/// The state returned on end-of-episode may lie outside the states-space if the player busts.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blackjack {
+    actions: Enumerated<BlackjackAction>,
+    states: Enumerated<(usize, usize, bool)>,
+    player: Hand,
+    dealer: Hand,
+    showing: usize,
+}
+
+impl Blackjack {
+    /// Constructs a blackjack environment.
+    pub fn new() -> Self {
+        let states = (12..=21)
+            .flat_map(|p| (1..=10).map(move |d| (p, d)))
+            .flat_map(|(p, d)| [false, true].into_iter().map(move |a| (p, d, a)));
+
+        Self {
+            actions: Enumerated::new([BlackjackAction::Stick, BlackjackAction::Hit]),
+            states: Enumerated::new(states),
+            player: Default::default(),
+            dealer: Default::default(),
+            showing: 1,
+        }
+    }
+}
+
+impl Default for Blackjack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Env<BlackjackAction, f64, (usize, usize, bool)> for Blackjack {
+    fn action_space(&self) -> &dyn Space<BlackjackAction> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<(usize, usize, bool)> {
+        &self.states
+    }
+
+    fn get_state(&self) -> (usize, usize, bool) {
+        (self.player.value(), self.showing, self.player.usable_ace())
+    }
+
+    fn call_mut<T>(&mut self, action: &BlackjackAction, rng: &mut T) -> (f64, (usize, usize, bool), bool)
+    where
+        T: Rng + ?Sized,
+    {
+        match action {
+            BlackjackAction::Hit => {
+                // Draw a card, losing if the player goes bust.
+                self.player.draw(rng);
+                match self.player.value() > 21 {
+                    false => (0., self.get_state(), false),
+                    true => (-1., self.get_state(), true),
+                }
+            }
+            BlackjackAction::Stick => {
+                // The dealer hits until seventeen or more.
+                while self.dealer.value() < 17 {
+                    self.dealer.draw(rng);
+                }
+                // Compare the hands, winning if the dealer goes bust or with a natural against a non-natural.
+                let (player, dealer) = (self.player.value(), self.dealer.value());
+                let natural = self.player.natural() && !self.dealer.natural();
+                let reward = match (dealer > 21 || natural, player.cmp(&dealer)) {
+                    (true, _) | (_, std::cmp::Ordering::Greater) => 1.,
+                    (_, std::cmp::Ordering::Equal) => 0.,
+                    (_, std::cmp::Ordering::Less) => -1.,
+                };
+
+                (reward, self.get_state(), true)
+            }
+        }
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Deal two cards to the dealer, showing the first one.
+        self.dealer = Default::default();
+        self.dealer.draw(rng);
+        self.showing = self.dealer.sum;
+        self.dealer.draw(rng);
+        // Deal two cards to the player, hitting while below twelve.
+        self.player = Default::default();
+        self.player.draw(rng);
+        self.player.draw(rng);
+        while self.player.value() < 12 {
+            self.player.draw(rng);
+        }
+
+        self
+    }
+}
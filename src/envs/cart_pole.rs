@@ -0,0 +1,161 @@
+use rand::Rng;
+
+use super::{Discretizer, Env, Observable};
+use crate::spaces::{Bounded, Discrete, Space};
+
+/// Cart-pole environment, as in Barto, Sutton & Anderson (1983) and Gym's `CartPole-v1`.
+///
+/// The continuous observation is the (cart position, cart velocity, pole angle, pole angular velocity)
+/// tuple, while the state is its discretization. The actions are push left and push right,
+/// each step is rewarded with +1 and the episode ends when the pole falls or the cart leaves the track.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CartPole {
+    actions: Discrete,
+    discretizer: Discretizer,
+    observations: Bounded,
+    observation: [f64; 4],
+    count: usize,
+    end: usize,
+}
+
+impl CartPole {
+    /// Gravitational acceleration.
+    const GRAVITY: f64 = 9.8;
+    /// Mass of the cart.
+    const MASS_CART: f64 = 1.0;
+    /// Mass of the pole.
+    const MASS_POLE: f64 = 0.1;
+    /// Half length of the pole.
+    const LENGTH: f64 = 0.5;
+    /// Magnitude of the applied force.
+    const FORCE: f64 = 10.0;
+    /// Seconds between state updates.
+    const TAU: f64 = 0.02;
+    /// Maximum absolute cart position.
+    pub const X_THRESHOLD: f64 = 2.4;
+    /// Maximum absolute pole angle, i.e. twelve degrees.
+    pub const THETA_THRESHOLD: f64 = 12. * std::f64::consts::PI / 180.;
+
+    /// Constructs a cart-pole environment given the discretizer of the observations.
+    pub fn new(discretizer: Discretizer) -> Self {
+        Self {
+            actions: Discrete(2),
+            discretizer,
+            observations: Bounded::new(
+                vec![
+                    -2. * Self::X_THRESHOLD,
+                    f64::NEG_INFINITY,
+                    -2. * Self::THETA_THRESHOLD,
+                    f64::NEG_INFINITY,
+                ],
+                vec![
+                    2. * Self::X_THRESHOLD,
+                    f64::INFINITY,
+                    2. * Self::THETA_THRESHOLD,
+                    f64::INFINITY,
+                ],
+            ),
+            observation: [0.; 4],
+            count: 0,
+            end: 500,
+        }
+    }
+
+    /// Constructs the discretizer of the observations given the number of bins of each dimension,
+    /// where velocities are bounded to typical values.
+    pub fn discretizer(bins: [usize; 4]) -> Discretizer {
+        Discretizer::new(
+            vec![-Self::X_THRESHOLD, -3., -Self::THETA_THRESHOLD, -3.5],
+            vec![Self::X_THRESHOLD, 3., Self::THETA_THRESHOLD, 3.5],
+            bins.to_vec(),
+        )
+    }
+
+    /// Sets the maximum number of steps per episode.
+    pub fn with_horizon(mut self, end: usize) -> Self {
+        self.end = end;
+
+        self
+    }
+
+    /// Gets the continuous observation of the environment.
+    pub fn observation(&self) -> [f64; 4] {
+        self.observation
+    }
+}
+
+impl Default for CartPole {
+    fn default() -> Self {
+        Self::new(Self::discretizer([3, 3, 6, 6]))
+    }
+}
+
+impl Observable for CartPole {
+    fn observation_space(&self) -> &Bounded {
+        &self.observations
+    }
+
+    fn get_observation(&self) -> Vec<f64> {
+        self.observation.to_vec()
+    }
+}
+
+impl Env<usize, f64, Vec<usize>> for CartPole {
+    fn action_space(&self) -> &dyn Space<usize> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<usize>> {
+        self.discretizer.space()
+    }
+
+    fn get_state(&self) -> Vec<usize> {
+        self.discretizer.call(&self.observation)
+    }
+
+    fn call_mut<T>(&mut self, action: &usize, _rng: &mut T) -> (f64, Vec<usize>, bool)
+    where
+        T: Rng + ?Sized,
+    {
+        let [x, x_dot, theta, theta_dot] = self.observation;
+        let force = match action {
+            0 => -Self::FORCE,
+            _ => Self::FORCE,
+        };
+        // Compute the accelerations.
+        let (sin, cos) = theta.sin_cos();
+        let total_mass = Self::MASS_CART + Self::MASS_POLE;
+        let pole_mass_length = Self::MASS_POLE * Self::LENGTH;
+        let temp = (force + pole_mass_length * theta_dot.powi(2) * sin) / total_mass;
+        let theta_acc = (Self::GRAVITY * sin - cos * temp)
+            / (Self::LENGTH * (4. / 3. - Self::MASS_POLE * cos.powi(2) / total_mass));
+        let x_acc = temp - pole_mass_length * theta_acc * cos / total_mass;
+        // Update the observation with Euler integration.
+        self.observation = [
+            x + Self::TAU * x_dot,
+            x_dot + Self::TAU * x_acc,
+            theta + Self::TAU * theta_dot,
+            theta_dot + Self::TAU * theta_acc,
+        ];
+        // Increment counter.
+        self.count += 1;
+
+        let [x, _, theta, _] = self.observation;
+        let is_done = x.abs() > Self::X_THRESHOLD || theta.abs() > Self::THETA_THRESHOLD || self.count >= self.end;
+
+        (1., self.get_state(), is_done)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the initial observation close to the equilibrium.
+        self.observation = [(); 4].map(|_| rng.gen_range(-0.05..0.05));
+        // Reset the time step counter.
+        self.count = 0;
+
+        self
+    }
+}
@@ -0,0 +1,78 @@
+use std::marker::PhantomData;
+
+use rand::Rng;
+
+use super::{Env, Observable};
+use crate::{
+    spaces::Space,
+    types::{Action, Reward, State},
+};
+
+/// Environment whose state is the continuous observation of an observable environment,
+/// in place of its discretization, e.g. for function approximation agents.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Continuous<E, S>
+where
+    S: State,
+{
+    _s_marker: PhantomData<S>,
+    env: E,
+}
+
+impl<E, S> Continuous<E, S>
+where
+    S: State,
+{
+    /// Constructs a continuous environment given an observable environment.
+    pub fn new(env: E) -> Self {
+        Self {
+            _s_marker: PhantomData,
+            env,
+        }
+    }
+
+    /// Gets the underlying environment.
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+}
+
+impl<A, R, S, E> Env<A, R, Vec<f64>> for Continuous<E, S>
+where
+    A: Action,
+    R: Reward,
+    S: State,
+    E: Env<A, R, S> + Observable,
+{
+    fn action_space(&self) -> &dyn Space<A> {
+        self.env.action_space()
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        self.env.observation_space()
+    }
+
+    fn get_state(&self) -> Vec<f64> {
+        self.env.get_observation()
+    }
+
+    fn call_mut<T>(&mut self, action: &A, rng: &mut T) -> (R, Vec<f64>, bool)
+    where
+        T: Rng + ?Sized,
+    {
+        // Discard the discrete state in favor of the observation.
+        let (reward, _, is_done) = self.env.call_mut(action, rng);
+
+        (reward, self.get_state(), is_done)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        self.env.reset(rng);
+
+        self
+    }
+}
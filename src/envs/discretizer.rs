@@ -0,0 +1,63 @@
+use crate::spaces::MultiDiscrete;
+
+/// Uniform discretization of a bounded continuous space into hashable states.
+///
+/// Each dimension is split into a number of equally sized bins within its bounds,
+/// where values outside the bounds are assigned to the nearest bin.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Discretizer {
+    low: Vec<f64>,
+    high: Vec<f64>,
+    space: MultiDiscrete,
+}
+
+impl Discretizer {
+    /// Constructs a discretizer given the lower bounds, the upper bounds and the number of bins of each dimension.
+    pub fn new(low: Vec<f64>, high: Vec<f64>, bins: Vec<usize>) -> Self {
+        assert!(
+            low.len() == high.len() && low.len() == bins.len(),
+            "Bounds and bins must have the same dimension"
+        );
+        assert!(
+            low.iter().zip(&high).all(|(l, h)| l < h),
+            "Lower bounds must be less than upper bounds"
+        );
+        assert!(bins.iter().all(|&b| b > 0), "Number of bins must be positive");
+
+        Self {
+            low,
+            high,
+            space: MultiDiscrete(bins),
+        }
+    }
+
+    /// Gets the number of bins of each dimension.
+    pub fn bins(&self) -> &[usize] {
+        &self.space.0
+    }
+
+    /// Maps a continuous value to its discrete state.
+    pub fn call(&self, x: &[f64]) -> Vec<usize> {
+        assert_eq!(
+            x.len(),
+            self.bins().len(),
+            "Value and bins must have the same dimension"
+        );
+
+        x.iter()
+            .zip(self.low.iter().zip(&self.high))
+            .zip(self.bins())
+            .map(|((x, (l, h)), &b)| {
+                // Compute the bin index, clamping values outside the bounds.
+                let i = ((x - l) / (h - l) * b as f64).floor();
+                i.clamp(0., (b - 1) as f64) as usize
+            })
+            .collect()
+    }
+
+    /// Gets the space of the discrete states, i.e. the cartesian product of the bins.
+    pub fn space(&self) -> &MultiDiscrete {
+        &self.space
+    }
+}
@@ -2,20 +2,23 @@ use std::fmt::Debug;
 
 use rand::Rng;
 
-use crate::types::{Action, Reward, State};
+use crate::{
+    spaces::{Bounded, Space},
+    types::{Action, Observation, Reward, State},
+};
 
 /// Definition of an environment.
 pub trait Env<A, R, S>: Clone + Debug
 where
     A: Action,
     R: Reward,
-    S: State,
+    S: Observation,
 {
-    /// Iterates of the action space.
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a A> + 'a>;
+    /// Gets the action space.
+    fn action_space(&self) -> &dyn Space<A>;
 
-    /// Iterates of the state space.
-    fn states_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a S> + 'a>;
+    /// Gets the state space.
+    fn state_space(&self) -> &dyn Space<S>;
 
     /// Gets current state of the environment.
     fn get_state(&self) -> S;
@@ -26,6 +29,74 @@ where
     where
         T: Rng + ?Sized;
 
-    /// Resets the environment state.
-    fn reset(&mut self) -> &mut Self;
+    /// Resets the environment state, sampling the initial state with given random number generator.
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized;
+
+    /// Gets the oracle of the environment, if the expected rewards are known.
+    fn oracle(&self) -> Option<&dyn Oracle<A, S>> {
+        None
+    }
+}
+
+/// Definition of an oracle, which knows the expected rewards of an environment.
+pub trait Oracle<A, S>
+where
+    A: Action,
+    S: Observation,
+{
+    /// Computes the expected reward of the given action-state pair.
+    fn expected_reward(&self, action: &A, state: &S) -> f64;
+
+    /// Computes the optimal action for given state.
+    fn optimal_action(&self, state: &S) -> A;
+
+    /// Computes the expected reward of the optimal action for given state.
+    fn optimal_reward(&self, state: &S) -> f64 {
+        self.expected_reward(&self.optimal_action(state), state)
+    }
+
+    /// Computes the pseudo-regret of the given action-state pair,
+    /// i.e. the difference between the optimal and the expected reward.
+    fn regret(&self, action: &A, state: &S) -> f64 {
+        self.optimal_reward(state) - self.expected_reward(action, state)
+    }
+}
+
+/// Definition of an environment whose (discrete) state is derived from a continuous observation.
+pub trait Observable {
+    /// Gets the observation space.
+    fn observation_space(&self) -> &Bounded;
+
+    /// Gets current continuous observation of the environment.
+    fn get_observation(&self) -> Vec<f64>;
+}
+
+/// Definition of a distribution with known mean.
+///
+/// Only [`rand_distr::Normal`] exposes its mean, while the parameters of the other distributions
+/// of `rand_distr` are private, hence their means must be given explicitly,
+/// e.g. with [`FarWest::with_means`](super::FarWest::with_means).
+pub trait Mean {
+    /// Gets the mean of the distribution.
+    fn mean(&self) -> f64;
+}
+
+impl Mean for rand_distr::Normal<f64> {
+    fn mean(&self) -> f64 {
+        rand_distr::Normal::mean(self)
+    }
+}
+
+/// Definition of a finite Markov decision process with known dynamics.
+pub trait FiniteMdp<A, R, S>: Env<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: State,
+{
+    /// Computes the dynamics of an action performed in a given state,
+    /// returning the (probability, reward, next state, end-of-episode flag) outcomes.
+    fn transitions(&self, state: &S, action: &A) -> Vec<(f64, R, S, bool)>;
 }
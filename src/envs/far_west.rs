@@ -3,21 +3,28 @@ use std::fmt::Debug;
 use rand::Rng;
 use rand_distr::Distribution;
 
-use super::Env;
+use super::{Env, Mean, Oracle};
+use crate::spaces::{Discrete, Space};
 
 /// Environment for multi-armed bandits given a sequence of distributions.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FarWest<D>
 where
     D: Clone + Debug + Distribution<f64>,
 {
-    // TODO: Remove this vector.
-    actions: Vec<usize>,
+    actions: Discrete,
     distributions: Vec<D>,
+    means: Option<Means>,
     count: usize,
     end: usize,
 }
 
+/// Oracle of a far-west environment, given the means of its distributions.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Means(Vec<f64>);
+
 impl<D> FarWest<D>
 where
     D: Clone + Debug + Distribution<f64>,
@@ -28,27 +35,57 @@ where
         I: Iterator<Item = D>,
     {
         let distributions: Vec<_> = distributions.collect();
-        let actions = (0..distributions.len()).collect();
 
         Self {
-            actions,
+            actions: Discrete(distributions.len()),
             distributions,
+            means: None,
             count: 0,
             end,
         }
     }
+
+    /// Enables the oracle of the environment, given the means of the distributions, e.g. for
+    /// distributions whose mean is not exposed by [`Mean`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the means and the distributions have different lengths, or if any mean is not finite.
+    pub fn with_means(mut self, means: Vec<f64>) -> Self {
+        assert_eq!(
+            means.len(),
+            self.distributions.len(),
+            "Means and distributions must have the same length"
+        );
+        assert!(means.iter().all(|m| m.is_finite()), "Means must be finite");
+        self.means = Some(Means(means));
+
+        self
+    }
+}
+
+impl<D> FarWest<D>
+where
+    D: Clone + Debug + Distribution<f64> + Mean,
+{
+    /// Enables the oracle of the environment, given the known means of the distributions.
+    pub fn with_oracle(self) -> Self {
+        let means = self.distributions.iter().map(Mean::mean).collect();
+
+        self.with_means(means)
+    }
 }
 
 impl<D> Env<usize, f64, ()> for FarWest<D>
 where
     D: Clone + Debug + Distribution<f64>,
 {
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a usize> + 'a> {
-        Box::new(self.actions.iter())
+    fn action_space(&self) -> &dyn Space<usize> {
+        &self.actions
     }
 
-    fn states_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a ()> + 'a> {
-        Box::new([()].iter())
+    fn state_space(&self) -> &dyn Space<()> {
+        &()
     }
 
     fn get_state(&self) {}
@@ -65,10 +102,34 @@ where
         (self.distributions[*action].sample(rng), (), is_done)
     }
 
-    fn reset(&mut self) -> &mut Self {
+    fn reset<T>(&mut self, _rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
         // Reset the time step counter.
         self.count = 0;
 
         self
     }
+
+    fn oracle(&self) -> Option<&dyn Oracle<usize, ()>> {
+        self.means.as_ref().map(|m| m as &dyn Oracle<usize, ()>)
+    }
+}
+
+impl Oracle<usize, ()> for Means {
+    fn expected_reward(&self, action: &usize, _state: &()) -> f64 {
+        self.0[*action]
+    }
+
+    fn optimal_action(&self, _state: &()) -> usize {
+        let means = &self.0;
+        // Select the action with the highest mean.
+        (0..means.len())
+            .reduce(|i, j| match means[i] < means[j] {
+                false => i,
+                true => j,
+            })
+            .expect("Unable to choose an action")
+    }
 }
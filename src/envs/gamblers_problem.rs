@@ -0,0 +1,116 @@
+use rand::Rng;
+
+use super::{Env, FiniteMdp};
+use crate::spaces::{Discrete, Space};
+
+/// Gambler's problem environment, as in Sutton & Barto (Example 4.3).
+///
+/// The state is the gambler's capital and the action `a` is the stake `a + 1`, which is
+/// capped to the capital and to the amount missing to reach the goal. Hence, the stake is always
+/// positive and every episode terminates with probability one, given `0 < p_head < 1`.
+/// The reward is +1 when the goal is reached and zero otherwise.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamblersProblem {
+    actions: Discrete,
+    states: Discrete,
+    p_head: f64,
+    state: usize,
+}
+
+impl GamblersProblem {
+    /// Constructs a gambler's problem environment given the goal capital and the probability of heads.
+    pub fn new(goal: usize, p_head: f64) -> Self {
+        assert!(goal >= 2, "Goal capital must be at least two");
+        assert!((0. ..=1.).contains(&p_head), "Probability of heads must be in [0, 1]");
+
+        Self {
+            actions: Discrete(goal / 2),
+            states: Discrete(goal + 1),
+            p_head,
+            state: goal / 2,
+        }
+    }
+
+    /// Gets the goal capital.
+    pub fn goal(&self) -> usize {
+        self.states.0 - 1
+    }
+
+    fn stake(&self, state: usize, action: usize) -> usize {
+        // Map the action to a positive stake, then cap it to the capital and to the amount missing to reach the goal.
+        usize::min(action + 1, usize::min(state, self.goal() - state))
+    }
+
+    fn outcome(&self, state: usize) -> (f64, usize, bool) {
+        match state {
+            0 => (0., state, true),
+            s if s == self.goal() => (1., state, true),
+            _ => (0., state, false),
+        }
+    }
+}
+
+impl Default for GamblersProblem {
+    fn default() -> Self {
+        Self::new(100, 0.4)
+    }
+}
+
+impl Env<usize, f64, usize> for GamblersProblem {
+    fn action_space(&self) -> &dyn Space<usize> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<usize> {
+        &self.states
+    }
+
+    fn get_state(&self) -> usize {
+        self.state
+    }
+
+    fn call_mut<T>(&mut self, action: &usize, rng: &mut T) -> (f64, usize, bool)
+    where
+        T: Rng + ?Sized,
+    {
+        let stake = self.stake(self.state, *action);
+        // Flip the coin.
+        let state = match rng.gen_bool(self.p_head) {
+            false => self.state - stake,
+            true => self.state + stake,
+        };
+        // Update the state.
+        self.state = state;
+
+        self.outcome(state)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample a non-terminal initial capital.
+        self.state = rng.gen_range(1..self.goal());
+
+        self
+    }
+}
+
+impl FiniteMdp<usize, f64, usize> for GamblersProblem {
+    fn transitions(&self, state: &usize, action: &usize) -> Vec<(f64, f64, usize, bool)> {
+        // Terminal states are absorbing.
+        if *state == 0 || *state == self.goal() {
+            return vec![(1., 0., *state, true)];
+        }
+
+        let stake = self.stake(*state, *action);
+        let (r_head, s_head, d_head) = self.outcome(state + stake);
+        let (r_tail, s_tail, d_tail) = self.outcome(state - stake);
+
+        vec![
+            (self.p_head, r_head, s_head, d_head),
+            (1. - self.p_head, r_tail, s_tail, d_tail),
+        ]
+    }
+}
@@ -0,0 +1,420 @@
+use rand::Rng;
+use rand_distr::{Distribution, Uniform};
+
+use super::Env;
+use crate::spaces::{Enumerated, Space};
+
+/// Action of a grid world agent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GridAction {
+    /// Move one cell up.
+    Up,
+    /// Move one cell down.
+    Down,
+    /// Move one cell left.
+    Left,
+    /// Move one cell right.
+    Right,
+    /// Move one cell up and one cell left.
+    UpLeft,
+    /// Move one cell up and one cell right.
+    UpRight,
+    /// Move one cell down and one cell left.
+    DownLeft,
+    /// Move one cell down and one cell right.
+    DownRight,
+    /// Stay in the current cell.
+    Stay,
+}
+
+impl GridAction {
+    /// Gets the (row, column) displacement of the action.
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Self::Up => (-1, 0),
+            Self::Down => (1, 0),
+            Self::Left => (0, -1),
+            Self::Right => (0, 1),
+            Self::UpLeft => (-1, -1),
+            Self::UpRight => (-1, 1),
+            Self::DownLeft => (1, -1),
+            Self::DownRight => (1, 1),
+            Self::Stay => (0, 0),
+        }
+    }
+
+    /// Gets the two actions perpendicular to the given one.
+    pub fn perpendicular(&self) -> [Self; 2] {
+        match self {
+            Self::Up | Self::Down => [Self::Left, Self::Right],
+            Self::Left | Self::Right => [Self::Up, Self::Down],
+            Self::UpLeft | Self::DownRight => [Self::UpRight, Self::DownLeft],
+            Self::UpRight | Self::DownLeft => [Self::UpLeft, Self::DownRight],
+            Self::Stay => [Self::Stay, Self::Stay],
+        }
+    }
+}
+
+/// Cell of a grid world layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cell {
+    /// Empty cell, denoted by `.`.
+    Empty,
+    /// Wall cell, which cannot be entered, denoted by `#`.
+    Wall,
+    /// Start cell, denoted by `S`.
+    Start,
+    /// Goal cell, which terminates the episode, denoted by `G`.
+    Goal,
+    /// Pit cell, which terminates the episode, denoted by `H`.
+    Pit,
+    /// Cliff cell, which sends the agent back to start, denoted by `C`.
+    Cliff,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            'S' => Ok(Self::Start),
+            'G' => Ok(Self::Goal),
+            'H' => Ok(Self::Pit),
+            'C' => Ok(Self::Cliff),
+            c => Err(c),
+        }
+    }
+}
+
+/// Grid world environment given an ASCII layout.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridWorld {
+    actions: Enumerated<GridAction>,
+    states: Enumerated<(usize, usize)>,
+    cells: Vec<Vec<Cell>>,
+    start: (usize, usize),
+    state: (usize, usize),
+    slip: f64,
+    wind: Vec<isize>,
+    stochastic_wind: bool,
+    step_reward: f64,
+    goal_reward: f64,
+    pit_reward: f64,
+    cliff_reward: f64,
+    rewards: Vec<Vec<Option<f64>>>,
+    count: usize,
+    end: Option<usize>,
+}
+
+impl GridWorld {
+    /// Constructs a grid world environment given an ASCII layout.
+    ///
+    /// Each non-empty line of the layout is a row of the grid, where each character is a cell:
+    /// `.` for empty, `#` for wall, `S` for start, `G` for goal, `H` for pit and `C` for cliff.
+    /// The layout must be rectangular and contain exactly one start cell.
+    pub fn new(layout: &str) -> Self {
+        // Parse the layout row by row.
+        let cells: Vec<Vec<Cell>> = layout
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(|c| Cell::try_from(c).unwrap_or_else(|c| panic!("Unable to parse grid cell '{}'", c)))
+                    .collect()
+            })
+            .collect();
+        // Assert the layout is rectangular.
+        assert!(!cells.is_empty(), "Grid layout must not be empty");
+        assert!(
+            cells.iter().all(|row| row.len() == cells[0].len()),
+            "Grid layout must be rectangular"
+        );
+        // Collect the non-wall cells as the states-space.
+        let states: Vec<_> = cells
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, cell)| ((i, j), cell)))
+            .filter(|(_, &cell)| cell != Cell::Wall)
+            .map(|(s, _)| s)
+            .collect();
+        // Get the start cell.
+        let mut start = states.iter().filter(|&&(i, j)| cells[i][j] == Cell::Start);
+        let (Some(&start), None) = (start.next(), start.next()) else {
+            panic!("Grid layout must contain exactly one start cell");
+        };
+        let wind = vec![0; cells[0].len()];
+        let rewards = vec![vec![None; cells[0].len()]; cells.len()];
+
+        Self {
+            actions: Enumerated::new([GridAction::Up, GridAction::Down, GridAction::Left, GridAction::Right]),
+            states: Enumerated::new(states),
+            cells,
+            start,
+            state: start,
+            slip: 0.,
+            wind,
+            stochastic_wind: false,
+            step_reward: 0.,
+            goal_reward: 1.,
+            pit_reward: 0.,
+            cliff_reward: -100.,
+            rewards,
+            count: 0,
+            end: None,
+        }
+    }
+
+    /// Constructs the Cliff Walking environment, as in Sutton & Barto (Example 6.6).
+    pub fn cliff_walking() -> Self {
+        Self::new(
+            "
+            ............
+            ............
+            ............
+            SCCCCCCCCCCG
+            ",
+        )
+        .with_step_reward(-1.)
+        .with_goal_reward(-1.)
+        .with_cliff_reward(-100.)
+    }
+
+    /// Constructs the Windy Gridworld environment, as in Sutton & Barto (Example 6.5),
+    /// optionally with king's moves (Exercise 6.9) and stochastic wind (Exercise 6.10).
+    pub fn windy_grid_world(king_moves: bool, stochastic_wind: bool) -> Self {
+        let env = Self::new(
+            "
+            ..........
+            ..........
+            ..........
+            S......G..
+            ..........
+            ..........
+            ..........
+            ",
+        )
+        .with_wind(vec![0, 0, 0, 1, 1, 1, 2, 2, 1, 0])
+        .with_stochastic_wind(stochastic_wind)
+        .with_step_reward(-1.)
+        .with_goal_reward(-1.);
+
+        match king_moves {
+            false => env,
+            true => env.with_king_moves(false),
+        }
+    }
+
+    /// Constructs the slippery FrozenLake 4x4 environment.
+    pub fn frozen_lake_4x4() -> Self {
+        Self::new(
+            "
+            S...
+            .H.H
+            ...H
+            H..G
+            ",
+        )
+        .with_slip(2. / 3.)
+    }
+
+    /// Constructs the slippery FrozenLake 8x8 environment.
+    pub fn frozen_lake_8x8() -> Self {
+        Self::new(
+            "
+            S.......
+            ........
+            ...H....
+            .....H..
+            ...H....
+            .HH...H.
+            .H..H.H.
+            ...H...G
+            ",
+        )
+        .with_slip(2. / 3.)
+    }
+
+    /// Enables king's moves, i.e. the four diagonal moves, optionally with the stay action.
+    pub fn with_king_moves(mut self, stay: bool) -> Self {
+        use GridAction::*;
+
+        self.actions = Enumerated::new([Up, Down, Left, Right, UpLeft, UpRight, DownLeft, DownRight]);
+        if stay {
+            self.actions.push(Stay);
+        }
+
+        self
+    }
+
+    /// Sets the probability of slipping into one of the two perpendicular directions.
+    pub fn with_slip(mut self, slip: f64) -> Self {
+        assert!((0. ..=1.).contains(&slip), "Slip probability must be in [0, 1]");
+        self.slip = slip;
+
+        self
+    }
+
+    /// Sets the upward wind strength for each column of the grid.
+    pub fn with_wind(mut self, wind: Vec<isize>) -> Self {
+        assert_eq!(wind.len(), self.cells[0].len(), "Wind must be given for each column");
+        self.wind = wind;
+
+        self
+    }
+
+    /// Sets whether the wind strength randomly varies by one cell, where present.
+    pub fn with_stochastic_wind(mut self, stochastic_wind: bool) -> Self {
+        self.stochastic_wind = stochastic_wind;
+
+        self
+    }
+
+    /// Sets the reward obtained for entering an empty or start cell.
+    pub fn with_step_reward(mut self, reward: f64) -> Self {
+        self.step_reward = reward;
+
+        self
+    }
+
+    /// Sets the reward obtained for entering a goal cell.
+    pub fn with_goal_reward(mut self, reward: f64) -> Self {
+        self.goal_reward = reward;
+
+        self
+    }
+
+    /// Sets the reward obtained for entering a pit cell.
+    pub fn with_pit_reward(mut self, reward: f64) -> Self {
+        self.pit_reward = reward;
+
+        self
+    }
+
+    /// Sets the reward obtained for entering a cliff cell.
+    pub fn with_cliff_reward(mut self, reward: f64) -> Self {
+        self.cliff_reward = reward;
+
+        self
+    }
+
+    /// Sets the reward obtained for entering the cell at given (row, column) position,
+    /// overriding the reward of its type, e.g. a goal cell worth more than the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside the grid or is a wall cell.
+    pub fn with_cell_reward(mut self, state: (usize, usize), reward: f64) -> Self {
+        let (rows, cols) = self.shape();
+        assert!(state.0 < rows && state.1 < cols, "Cell must be inside the grid");
+        assert_ne!(self.cell(&state), Cell::Wall, "Cell must not be a wall");
+        self.rewards[state.0][state.1] = Some(reward);
+
+        self
+    }
+
+    /// Sets the maximum number of steps per episode.
+    pub fn with_horizon(mut self, end: usize) -> Self {
+        self.end = Some(end);
+
+        self
+    }
+
+    /// Gets the cell at given (row, column) position.
+    pub fn cell(&self, state: &(usize, usize)) -> Cell {
+        self.cells[state.0][state.1]
+    }
+
+    /// Gets the (rows, columns) shape of the grid.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.cells.len(), self.cells[0].len())
+    }
+
+    fn next_state<T>(&self, action: &GridAction, rng: &mut T) -> (usize, usize)
+    where
+        T: Rng + ?Sized,
+    {
+        let (rows, cols) = self.shape();
+        let (i, j) = self.state;
+        // With given probability, slip into a perpendicular direction.
+        let action = match self.slip > 0. && Uniform::new(0., 1.).sample(rng) < self.slip {
+            false => *action,
+            true => action.perpendicular()[rng.gen_range(0..2)],
+        };
+        let (di, dj) = action.delta();
+        // Compute the wind effect of the current column.
+        let mut wind = self.wind[j];
+        if self.stochastic_wind && wind != 0 {
+            wind += rng.gen_range(-1..=1);
+        }
+        // Move within the grid boundaries.
+        let i = (i as isize + di - wind).clamp(0, rows as isize - 1) as usize;
+        let j = (j as isize + dj).clamp(0, cols as isize - 1) as usize;
+        // Walls cannot be entered.
+        match self.cells[i][j] {
+            Cell::Wall => self.state,
+            _ => (i, j),
+        }
+    }
+}
+
+impl Env<GridAction, f64, (usize, usize)> for GridWorld {
+    fn action_space(&self) -> &dyn Space<GridAction> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<(usize, usize)> {
+        &self.states
+    }
+
+    fn get_state(&self) -> (usize, usize) {
+        self.state
+    }
+
+    fn call_mut<T>(&mut self, action: &GridAction, rng: &mut T) -> (f64, (usize, usize), bool)
+    where
+        T: Rng + ?Sized,
+    {
+        // Increment counter.
+        self.count += 1;
+        // Compute the next state.
+        self.state = self.next_state(action, rng);
+        // Compute the reward and end-of-episode flag given the entered cell, where the reward
+        // of the cell, if any, overrides the reward of its type.
+        let cell_reward = self.rewards[self.state.0][self.state.1];
+        let (reward, mut is_done) = match self.cell(&self.state) {
+            Cell::Goal => (self.goal_reward, true),
+            Cell::Pit => (self.pit_reward, true),
+            Cell::Cliff => {
+                // Falling off the cliff sends the agent back to start.
+                self.state = self.start;
+                (self.cliff_reward, false)
+            }
+            _ => (self.step_reward, false),
+        };
+        let reward = cell_reward.unwrap_or(reward);
+        // Check if we reached the end of the episode.
+        if let Some(end) = self.end {
+            is_done |= self.count >= end;
+        }
+
+        (reward, self.state, is_done)
+    }
+
+    fn reset<T>(&mut self, _rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Reset the agent position.
+        self.state = self.start;
+        // Reset the time step counter.
+        self.count = 0;
+
+        self
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
+use rand_distr::{Distribution, Poisson};
+
+use super::{Env, FiniteMdp};
+use crate::spaces::{Discrete, Enumerated, Space};
+
+/// Jack's car rental environment, as in Sutton & Barto (Example 4.2).
+///
+/// The state is the number of cars at the two locations at the end of the day,
+/// and the action is the net number of cars moved overnight from the first to the second location.
+/// Each rented car earns $10, while each moved car costs $2.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JacksCarRental {
+    actions: Enumerated<isize>,
+    states: (Discrete, Discrete),
+    max_cars: usize,
+    requests: (f64, f64),
+    returns: (f64, f64),
+    state: (usize, usize),
+    count: usize,
+    end: usize,
+}
+
+impl JacksCarRental {
+    /// Constructs a Jack's car rental environment given a time horizon.
+    pub fn new(end: usize) -> Self {
+        let (max_cars, max_move) = (20, 5);
+
+        Self {
+            actions: Enumerated::new(-max_move..=max_move),
+            states: (Discrete(max_cars + 1), Discrete(max_cars + 1)),
+            max_cars,
+            requests: (3., 4.),
+            returns: (3., 2.),
+            state: (max_cars / 2, max_cars / 2),
+            count: 0,
+            end,
+        }
+    }
+
+    fn moved(&self, state: &(usize, usize), action: isize) -> ((usize, usize), isize) {
+        // Move at most the available cars, within the parking capacity.
+        let action = action.clamp(-(state.1 as isize), state.0 as isize);
+        let i = usize::min((state.0 as isize - action) as usize, self.max_cars);
+        let j = usize::min((state.1 as isize + action) as usize, self.max_cars);
+
+        ((i, j), action)
+    }
+
+    /// Computes the distribution of (next cars, expected rentals) at a location, given the available cars.
+    fn location(&self, cars: usize, requests: f64, returns: f64) -> BTreeMap<usize, (f64, f64)> {
+        let mut outcomes = BTreeMap::new();
+        for (p_req, rented) in poisson_capped(requests, cars) {
+            for (p_ret, returned) in poisson_capped(returns, self.max_cars - (cars - rented)) {
+                let (p, e) = outcomes.entry(cars - rented + returned).or_insert((0., 0.));
+                *p += p_req * p_ret;
+                *e += p_req * p_ret * rented as f64;
+            }
+        }
+        // Normalize the expected rentals given the next cars.
+        outcomes
+            .values_mut()
+            .filter(|(p, _)| *p > 0.)
+            .for_each(|(p, e)| *e /= *p);
+
+        outcomes
+    }
+}
+
+impl Default for JacksCarRental {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+/// Computes the Poisson probabilities of the outcomes `0..=cap`, where the last one accumulates the tail.
+fn poisson_capped(lambda: f64, cap: usize) -> Vec<(f64, usize)> {
+    let mut p = (-lambda).exp();
+    let mut outcomes = Vec::with_capacity(cap + 1);
+    let mut total = 0.;
+    for k in 0..cap {
+        outcomes.push((p, k));
+        total += p;
+        p *= lambda / (k + 1) as f64;
+    }
+    outcomes.push((f64::max(1. - total, 0.), cap));
+
+    outcomes
+}
+
+impl Env<isize, f64, (usize, usize)> for JacksCarRental {
+    fn action_space(&self) -> &dyn Space<isize> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<(usize, usize)> {
+        &self.states
+    }
+
+    fn get_state(&self) -> (usize, usize) {
+        self.state
+    }
+
+    fn call_mut<T>(&mut self, action: &isize, rng: &mut T) -> (f64, (usize, usize), bool)
+    where
+        T: Rng + ?Sized,
+    {
+        // Move the cars overnight.
+        let ((i, j), action) = self.moved(&self.state, *action);
+        // Sample the requests, rent the available cars, then sample the returns.
+        let mut sample = |lambda: f64| {
+            Poisson::new(lambda)
+                .expect("Unable to construct Poisson distribution")
+                .sample(rng) as usize
+        };
+        let (rented_i, rented_j) = (
+            usize::min(sample(self.requests.0), i),
+            usize::min(sample(self.requests.1), j),
+        );
+        let (returned_i, returned_j) = (sample(self.returns.0), sample(self.returns.1));
+        // Update the state.
+        self.state = (
+            usize::min(i - rented_i + returned_i, self.max_cars),
+            usize::min(j - rented_j + returned_j, self.max_cars),
+        );
+        // Increment counter.
+        self.count += 1;
+
+        let reward = 10. * (rented_i + rented_j) as f64 - 2. * action.abs() as f64;
+
+        (reward, self.state, self.count >= self.end)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the initial number of cars at each location.
+        self.state = (rng.gen_range(0..=self.max_cars), rng.gen_range(0..=self.max_cars));
+        // Reset the time step counter.
+        self.count = 0;
+
+        self
+    }
+}
+
+impl FiniteMdp<isize, f64, (usize, usize)> for JacksCarRental {
+    /// Computes the dynamics of an action performed in a given state, where the
+    /// reward of each outcome is the expected reward conditioned on the next state.
+    fn transitions(&self, state: &(usize, usize), action: &isize) -> Vec<(f64, f64, (usize, usize), bool)> {
+        // Move the cars overnight.
+        let ((i, j), action) = self.moved(state, *action);
+        // Compute the outcomes of the two locations independently.
+        let first = self.location(i, self.requests.0, self.returns.0);
+        let second = self.location(j, self.requests.1, self.returns.1);
+
+        first
+            .iter()
+            .flat_map(|(&i, &(p_i, e_i))| {
+                second
+                    .iter()
+                    .map(move |(&j, &(p_j, e_j))| ((i, j), p_i * p_j, e_i + e_j))
+            })
+            .map(|(s, p, e)| (p, 10. * e - 2. * action.abs() as f64, s, false))
+            .collect()
+    }
+}
@@ -1,5 +1,44 @@
 mod env;
-pub use env::Env;
+pub use env::{Env, FiniteMdp, Mean, Observable, Oracle};
 
 mod far_west;
 pub use far_west::FarWest;
+
+mod grid_world;
+pub use grid_world::{Cell, GridAction, GridWorld};
+
+mod taxi;
+pub use taxi::{Taxi, TaxiAction};
+
+mod blackjack;
+pub use blackjack::{Blackjack, BlackjackAction};
+
+mod random_walk;
+pub use random_walk::RandomWalk;
+
+mod gamblers_problem;
+pub use gamblers_problem::GamblersProblem;
+
+mod jacks_car_rental;
+pub use jacks_car_rental::JacksCarRental;
+
+mod discretizer;
+pub use discretizer::Discretizer;
+
+mod continuous;
+pub use continuous::Continuous;
+
+mod mountain_car;
+pub use mountain_car::MountainCar;
+
+mod cart_pole;
+pub use cart_pole::CartPole;
+
+mod acrobot;
+pub use acrobot::Acrobot;
+
+mod pendulum;
+pub use pendulum::Pendulum;
+
+mod non_stationary_bandit;
+pub use non_stationary_bandit::{Drift, NonStationaryBandit};
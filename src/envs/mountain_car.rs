@@ -0,0 +1,129 @@
+use rand::Rng;
+
+use super::{Discretizer, Env, Observable};
+use crate::spaces::{Bounded, Discrete, Space};
+
+/// Mountain car environment, as in Sutton & Barto (Example 10.1) and Gym's `MountainCar-v0`.
+///
+/// The continuous observation is the (position, velocity) pair of the car,
+/// while the state is its discretization. The actions are push left, no push and push right,
+/// each step is rewarded with -1 and the episode ends when the car reaches the goal.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MountainCar {
+    actions: Discrete,
+    discretizer: Discretizer,
+    observations: Bounded,
+    observation: [f64; 2],
+    count: usize,
+    end: usize,
+}
+
+impl MountainCar {
+    /// Bounds of the car position.
+    pub const POSITION: (f64, f64) = (-1.2, 0.6);
+    /// Bounds of the car velocity.
+    pub const VELOCITY: (f64, f64) = (-0.07, 0.07);
+    /// Position of the goal.
+    pub const GOAL: f64 = 0.5;
+
+    /// Constructs a mountain car environment given the discretizer of the observations.
+    pub fn new(discretizer: Discretizer) -> Self {
+        Self {
+            actions: Discrete(3),
+            discretizer,
+            observations: Bounded::new(
+                vec![Self::POSITION.0, Self::VELOCITY.0],
+                vec![Self::POSITION.1, Self::VELOCITY.1],
+            ),
+            observation: [-0.5, 0.],
+            count: 0,
+            end: 200,
+        }
+    }
+
+    /// Constructs the discretizer of the observations given the number of bins of each dimension.
+    pub fn discretizer(bins: [usize; 2]) -> Discretizer {
+        Discretizer::new(
+            vec![Self::POSITION.0, Self::VELOCITY.0],
+            vec![Self::POSITION.1, Self::VELOCITY.1],
+            bins.to_vec(),
+        )
+    }
+
+    /// Sets the maximum number of steps per episode.
+    pub fn with_horizon(mut self, end: usize) -> Self {
+        self.end = end;
+
+        self
+    }
+
+    /// Gets the continuous observation of the environment.
+    pub fn observation(&self) -> [f64; 2] {
+        self.observation
+    }
+}
+
+impl Default for MountainCar {
+    fn default() -> Self {
+        Self::new(Self::discretizer([20, 20]))
+    }
+}
+
+impl Observable for MountainCar {
+    fn observation_space(&self) -> &Bounded {
+        &self.observations
+    }
+
+    fn get_observation(&self) -> Vec<f64> {
+        self.observation.to_vec()
+    }
+}
+
+impl Env<usize, f64, Vec<usize>> for MountainCar {
+    fn action_space(&self) -> &dyn Space<usize> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<usize>> {
+        self.discretizer.space()
+    }
+
+    fn get_state(&self) -> Vec<usize> {
+        self.discretizer.call(&self.observation)
+    }
+
+    fn call_mut<T>(&mut self, action: &usize, _rng: &mut T) -> (f64, Vec<usize>, bool)
+    where
+        T: Rng + ?Sized,
+    {
+        let [mut position, mut velocity] = self.observation;
+        // Update the velocity given the applied force and the gravity.
+        velocity += (*action as f64 - 1.) * 0.001 - 0.0025 * (3. * position).cos();
+        velocity = velocity.clamp(Self::VELOCITY.0, Self::VELOCITY.1);
+        // Update the position, stopping the car at the left boundary.
+        position = (position + velocity).clamp(Self::POSITION.0, Self::POSITION.1);
+        if position <= Self::POSITION.0 && velocity < 0. {
+            velocity = 0.;
+        }
+        self.observation = [position, velocity];
+        // Increment counter.
+        self.count += 1;
+
+        let is_done = position >= Self::GOAL || self.count >= self.end;
+
+        (-1., self.get_state(), is_done)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the initial position at the bottom of the valley.
+        self.observation = [rng.gen_range(-0.6..-0.4), 0.];
+        // Reset the time step counter.
+        self.count = 0;
+
+        self
+    }
+}
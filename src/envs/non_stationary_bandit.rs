@@ -0,0 +1,179 @@
+use std::f64::consts::PI;
+
+use rand::Rng;
+use rand_distr::{Distribution, Normal, StandardNormal};
+
+use super::{Env, Oracle};
+use crate::spaces::{Discrete, Space};
+
+/// Drift of the arm means of a non-stationary bandit.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Drift {
+    /// Arm means perform independent Gaussian random walks with given standard deviation.
+    RandomWalk(f64),
+    /// Arm means switch abruptly to the given values at the given change-points.
+    Piecewise(Vec<(usize, Vec<f64>)>),
+    /// Arm means oscillate with given amplitude and period, with phases evenly spaced across arms.
+    Periodic(f64, f64),
+}
+
+/// Environment for multi-armed bandits whose arm means change over time.
+///
+/// Rewards are sampled from a normal distribution centered on the current arm mean,
+/// with given standard deviation (noise).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NonStationaryBandit {
+    actions: Discrete,
+    initial_means: Vec<f64>,
+    means: Vec<f64>,
+    noise: f64,
+    drift: Drift,
+    count: usize,
+    end: usize,
+}
+
+impl NonStationaryBandit {
+    /// Constructs a non-stationary bandit given the initial arm means, their drift and a time horizon.
+    pub fn new<I>(means: I, drift: Drift, end: usize) -> Self
+    where
+        I: Iterator<Item = f64>,
+    {
+        let means: Vec<_> = means.collect();
+        // Assert the drift is well-defined, e.g. the change-points are consistent with the arms.
+        match &drift {
+            Drift::RandomWalk(sigma) => assert!(*sigma >= 0., "Random walk deviation must be non-negative"),
+            Drift::Piecewise(change_points) => {
+                assert!(
+                    change_points.iter().all(|(_, m)| m.len() == means.len()),
+                    "Change-points must set a mean for each arm"
+                );
+                assert!(
+                    change_points.windows(2).all(|w| w[0].0 < w[1].0),
+                    "Change-points must be strictly increasing"
+                );
+            }
+            Drift::Periodic(_, period) => assert!(*period > 0., "Period must be positive"),
+        }
+
+        Self {
+            actions: Discrete(means.len()),
+            initial_means: means.clone(),
+            means,
+            noise: 1.,
+            drift,
+            count: 0,
+            end,
+        }
+    }
+
+    /// Constructs the non-stationary testbed, as in Sutton & Barto (Exercise 2.5),
+    /// where all arm means start at zero and perform random walks with standard deviation 0.01.
+    pub fn testbed(arms: usize, end: usize) -> Self {
+        Self::new(std::iter::repeat_n(0., arms), Drift::RandomWalk(0.01), end)
+    }
+
+    /// Sets the standard deviation of the rewards.
+    pub fn with_noise(mut self, noise: f64) -> Self {
+        assert!(noise >= 0., "Reward noise must be non-negative");
+        self.noise = noise;
+
+        self
+    }
+
+    /// Gets the current (true) arm means.
+    pub fn means(&self) -> &[f64] {
+        &self.means
+    }
+
+    fn drift<T>(&mut self, rng: &mut T)
+    where
+        T: Rng + ?Sized,
+    {
+        match &self.drift {
+            Drift::RandomWalk(sigma) => {
+                let normal = Normal::new(0., *sigma).expect("Unable to construct Normal distribution");
+                self.means.iter_mut().for_each(|m| *m += normal.sample(rng));
+            }
+            Drift::Piecewise(change_points) => {
+                // Switch to the means of the last reached change-point.
+                if let Some((_, means)) = change_points.iter().rev().find(|(t, _)| *t <= self.count) {
+                    self.means.clone_from(means);
+                }
+            }
+            Drift::Periodic(amplitude, period) => {
+                let k = self.means.len() as f64;
+                let t = 2. * PI * self.count as f64 / period;
+                self.means = self
+                    .initial_means
+                    .iter()
+                    .enumerate()
+                    .map(|(i, m)| m + amplitude * (t + 2. * PI * i as f64 / k).sin())
+                    .collect();
+            }
+        }
+    }
+}
+
+impl Env<usize, f64, ()> for NonStationaryBandit {
+    fn action_space(&self) -> &dyn Space<usize> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<()> {
+        &()
+    }
+
+    fn get_state(&self) {}
+
+    fn call_mut<T>(&mut self, action: &usize, rng: &mut T) -> (f64, (), bool)
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the reward around the current mean.
+        let reward = self.means[*action] + self.noise * rng.sample::<f64, _>(StandardNormal);
+        // Increment counter.
+        self.count += 1;
+        // Drift the arm means.
+        self.drift(rng);
+
+        (reward, (), self.count >= self.end)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Reset the time step counter.
+        self.count = 0;
+        // Reset the arm means.
+        self.means.clone_from(&self.initial_means);
+        // Apply the schedule of the initial time step, if any.
+        if !matches!(self.drift, Drift::RandomWalk(_)) {
+            self.drift(rng);
+        }
+
+        self
+    }
+
+    fn oracle(&self) -> Option<&dyn Oracle<usize, ()>> {
+        Some(self)
+    }
+}
+
+impl Oracle<usize, ()> for NonStationaryBandit {
+    fn expected_reward(&self, action: &usize, _state: &()) -> f64 {
+        self.means[*action]
+    }
+
+    fn optimal_action(&self, _state: &()) -> usize {
+        // Select the action with the highest current mean.
+        (0..self.means.len())
+            .reduce(|i, j| match self.means[i] < self.means[j] {
+                false => i,
+                true => j,
+            })
+            .expect("Unable to choose an action")
+    }
+}
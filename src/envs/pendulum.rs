@@ -0,0 +1,111 @@
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+use super::Env;
+use crate::spaces::{Bounded, Space};
+
+/// Pendulum environment, as in Gym's `Pendulum-v1`, with continuous states and actions.
+///
+/// The state is the (cosine of the angle, sine of the angle, angular velocity) tuple of the pendulum,
+/// where the angle is zero when upright. The action is the torque applied to the free end, clipped to
+/// the action space, each step is penalized by the distance from the upright position, the angular
+/// velocity and the torque, and the episode ends when the time horizon is reached.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pendulum {
+    actions: Bounded,
+    states: Bounded,
+    theta: f64,
+    theta_dot: f64,
+    count: usize,
+    end: usize,
+}
+
+impl Pendulum {
+    /// Gravitational acceleration.
+    const GRAVITY: f64 = 10.;
+    /// Mass of the pendulum.
+    const MASS: f64 = 1.;
+    /// Length of the pendulum.
+    const LENGTH: f64 = 1.;
+    /// Seconds between state updates.
+    const DT: f64 = 0.05;
+    /// Maximum absolute angular velocity.
+    pub const MAX_SPEED: f64 = 8.;
+    /// Maximum absolute torque.
+    pub const MAX_TORQUE: f64 = 2.;
+
+    /// Constructs a pendulum environment given the maximum number of steps per episode.
+    pub fn new(end: usize) -> Self {
+        Self {
+            actions: Bounded::new(vec![-Self::MAX_TORQUE], vec![Self::MAX_TORQUE]),
+            states: Bounded::new(vec![-1., -1., -Self::MAX_SPEED], vec![1., 1., Self::MAX_SPEED]),
+            theta: PI,
+            theta_dot: 0.,
+            count: 0,
+            end,
+        }
+    }
+}
+
+impl Default for Pendulum {
+    fn default() -> Self {
+        Self::new(200)
+    }
+}
+
+/// Wraps an angle into `[-pi, pi)`.
+fn wrap(x: f64) -> f64 {
+    (x + PI).rem_euclid(2. * PI) - PI
+}
+
+impl Env<Vec<f64>, f64, Vec<f64>> for Pendulum {
+    fn action_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.states
+    }
+
+    fn get_state(&self) -> Vec<f64> {
+        vec![self.theta.cos(), self.theta.sin(), self.theta_dot]
+    }
+
+    fn call_mut<T>(&mut self, action: &Vec<f64>, _rng: &mut T) -> (f64, Vec<f64>, bool)
+    where
+        T: Rng + ?Sized,
+    {
+        // Clip the torque to the action space.
+        let [torque]: [f64; 1] = self
+            .actions
+            .clip(action)
+            .try_into()
+            .expect("Torque must be one-dimensional");
+        // Penalize the distance from the upright position, the velocity and the effort.
+        let cost = wrap(self.theta).powi(2) + 0.1 * self.theta_dot.powi(2) + 0.001 * torque.powi(2);
+        // Update the angular velocity, then the angle, with semi-implicit Euler integration.
+        let (g, m, l) = (Self::GRAVITY, Self::MASS, Self::LENGTH);
+        self.theta_dot += (3. * g / (2. * l) * self.theta.sin() + 3. / (m * l.powi(2)) * torque) * Self::DT;
+        self.theta_dot = self.theta_dot.clamp(-Self::MAX_SPEED, Self::MAX_SPEED);
+        self.theta = wrap(self.theta + self.theta_dot * Self::DT);
+        // Increment counter.
+        self.count += 1;
+
+        (-cost, self.get_state(), self.count >= self.end)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the initial angle and angular velocity uniformly.
+        self.theta = rng.gen_range(-PI..PI);
+        self.theta_dot = rng.gen_range(-1.0..1.0);
+        // Reset the time step counter.
+        self.count = 0;
+
+        self
+    }
+}
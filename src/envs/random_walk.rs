@@ -0,0 +1,124 @@
+use rand::Rng;
+
+use super::{Env, FiniteMdp};
+use crate::spaces::{Discrete, Space};
+
+/// Random walk environment for prediction, as in Sutton & Barto (Example 6.2 and Example 7.1).
+///
+/// The states are numbered from left to right, where the leftmost and rightmost
+/// states are terminal. The walk starts in the center and moves left or right
+/// with equal probability, regardless of the (nil) action.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomWalk {
+    states: Discrete,
+    left_reward: f64,
+    right_reward: f64,
+    state: usize,
+}
+
+impl RandomWalk {
+    /// Constructs a random walk environment given the number of non-terminal states
+    /// and the rewards obtained for terminating on the left and on the right.
+    pub fn new(n: usize, left_reward: f64, right_reward: f64) -> Self {
+        assert!(n % 2 == 1, "Random walk must have an odd number of non-terminal states");
+
+        Self {
+            states: Discrete(n + 2),
+            left_reward,
+            right_reward,
+            state: (n + 2) / 2,
+        }
+    }
+
+    /// Constructs the 5-state random walk, with rewards 0 and +1.
+    pub fn five_states() -> Self {
+        Self::new(5, 0., 1.)
+    }
+
+    /// Constructs the 19-state random walk, with rewards -1 and +1.
+    pub fn nineteen_states() -> Self {
+        Self::new(19, -1., 1.)
+    }
+
+    /// Computes the true (undiscounted) value of each state, terminal states included.
+    pub fn true_values(&self) -> Vec<f64> {
+        let n = self.states.0 - 1;
+
+        (0..=n)
+            .map(|s| match s {
+                0 => 0.,
+                s if s == n => 0.,
+                s => self.left_reward + (self.right_reward - self.left_reward) * s as f64 / n as f64,
+            })
+            .collect()
+    }
+
+    fn step(&self, state: usize, right: bool) -> (f64, usize, bool) {
+        let n = self.states.0 - 1;
+        let state = match right {
+            false => state - 1,
+            true => state + 1,
+        };
+
+        match state {
+            0 => (self.left_reward, state, true),
+            s if s == n => (self.right_reward, state, true),
+            _ => (0., state, false),
+        }
+    }
+}
+
+impl Env<(), f64, usize> for RandomWalk {
+    fn action_space(&self) -> &dyn Space<()> {
+        &()
+    }
+
+    fn state_space(&self) -> &dyn Space<usize> {
+        &self.states
+    }
+
+    fn get_state(&self) -> usize {
+        self.state
+    }
+
+    fn call_mut<T>(&mut self, _action: &(), rng: &mut T) -> (f64, usize, bool)
+    where
+        T: Rng + ?Sized,
+    {
+        // Move left or right with equal probability.
+        let (reward, state, is_done) = self.step(self.state, rng.gen_bool(0.5));
+        // Update the state.
+        self.state = state;
+
+        (reward, state, is_done)
+    }
+
+    fn reset<T>(&mut self, _rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Start from the center state.
+        self.state = self.states.0 / 2;
+
+        self
+    }
+}
+
+impl FiniteMdp<(), f64, usize> for RandomWalk {
+    fn transitions(&self, state: &usize, _action: &()) -> Vec<(f64, f64, usize, bool)> {
+        // Terminal states are absorbing.
+        if *state == 0 || *state == self.states.0 - 1 {
+            return vec![(1., 0., *state, true)];
+        }
+
+        [false, true]
+            .into_iter()
+            .map(|right| {
+                let (reward, state, is_done) = self.step(*state, right);
+
+                (0.5, reward, state, is_done)
+            })
+            .collect()
+    }
+}
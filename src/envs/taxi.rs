@@ -0,0 +1,142 @@
+use rand::Rng;
+
+use super::Env;
+use crate::spaces::{Enumerated, Space};
+
+/// Map of the taxi environment, where `:` denotes a passage and `|` a wall.
+const MAP: [&[u8]; 7] = [
+    b"+---------+",
+    b"|R: | : :G|",
+    b"| : | : : |",
+    b"| : : : : |",
+    b"| | : | : |",
+    b"|Y| : |B: |",
+    b"+---------+",
+];
+
+/// Pick-up and drop-off locations of the taxi environment, i.e. R, G, Y and B.
+const LOCATIONS: [(usize, usize); 4] = [(0, 0), (0, 4), (4, 0), (4, 3)];
+
+/// Action of a taxi agent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TaxiAction {
+    /// Move one cell south.
+    South,
+    /// Move one cell north.
+    North,
+    /// Move one cell east.
+    East,
+    /// Move one cell west.
+    West,
+    /// Pick up the passenger.
+    Pickup,
+    /// Drop off the passenger.
+    Dropoff,
+}
+
+/// Taxi environment, as in Dietterich (2000) and Gym's `Taxi-v3`.
+///
+/// The state is the (taxi row, taxi column, passenger location, destination) tuple,
+/// where the passenger location is the index of a pick-up location, or 4 if in the taxi.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Taxi {
+    actions: Enumerated<TaxiAction>,
+    states: Enumerated<(usize, usize, usize, usize)>,
+    state: (usize, usize, usize, usize),
+    count: usize,
+    end: usize,
+}
+
+impl Taxi {
+    /// Constructs a taxi environment given a time horizon.
+    pub fn new(end: usize) -> Self {
+        use TaxiAction::*;
+
+        let states = (0..5)
+            .flat_map(|i| (0..5).map(move |j| (i, j)))
+            .flat_map(|(i, j)| (0..5).map(move |p| (i, j, p)))
+            .flat_map(|(i, j, p)| (0..4).map(move |d| (i, j, p, d)));
+
+        Self {
+            actions: Enumerated::new([South, North, East, West, Pickup, Dropoff]),
+            states: Enumerated::new(states),
+            state: (0, 0, 0, 1),
+            count: 0,
+            end,
+        }
+    }
+}
+
+impl Default for Taxi {
+    fn default() -> Self {
+        Self::new(200)
+    }
+}
+
+impl Env<TaxiAction, f64, (usize, usize, usize, usize)> for Taxi {
+    fn action_space(&self) -> &dyn Space<TaxiAction> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<(usize, usize, usize, usize)> {
+        &self.states
+    }
+
+    fn get_state(&self) -> (usize, usize, usize, usize) {
+        self.state
+    }
+
+    fn call_mut<T>(&mut self, action: &TaxiAction, _rng: &mut T) -> (f64, (usize, usize, usize, usize), bool)
+    where
+        T: Rng + ?Sized,
+    {
+        let (i, j, p, d) = self.state;
+        // Increment counter.
+        self.count += 1;
+        // Compute the effect of the action.
+        let (reward, state, is_done) = match action {
+            TaxiAction::South => (-1., (usize::min(i + 1, 4), j, p, d), false),
+            TaxiAction::North => (-1., (i.saturating_sub(1), j, p, d), false),
+            // Moving east or west is allowed only through passages.
+            TaxiAction::East => match MAP[i + 1][2 * j + 2] {
+                b':' => (-1., (i, j + 1, p, d), false),
+                _ => (-1., (i, j, p, d), false),
+            },
+            TaxiAction::West => match MAP[i + 1][2 * j] {
+                b':' => (-1., (i, j - 1, p, d), false),
+                _ => (-1., (i, j, p, d), false),
+            },
+            // Pick up the passenger only at its location.
+            TaxiAction::Pickup => match p < 4 && LOCATIONS[p] == (i, j) {
+                false => (-10., (i, j, p, d), false),
+                true => (-1., (i, j, 4, d), false),
+            },
+            // Drop off the passenger only at a pick-up location, completing the ride at its destination.
+            TaxiAction::Dropoff => match LOCATIONS.iter().position(|&l| l == (i, j)) {
+                Some(l) if p == 4 && l == d => (20., (i, j, d, d), true),
+                Some(l) if p == 4 => (-1., (i, j, l, d), false),
+                _ => (-10., (i, j, p, d), false),
+            },
+        };
+        // Update the state.
+        self.state = state;
+
+        (reward, state, is_done || self.count >= self.end)
+    }
+
+    fn reset<T>(&mut self, rng: &mut T) -> &mut Self
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample the taxi position, the passenger location and a different destination.
+        let p = rng.gen_range(0..4);
+        let d = (p + rng.gen_range(1..4)) % 4;
+        self.state = (rng.gen_range(0..5), rng.gen_range(0..5), p, d);
+        // Reset the time step counter.
+        self.count = 0;
+
+        self
+    }
+}
@@ -0,0 +1,41 @@
+use polars::prelude::PolarsError;
+
+/// Error of the crate.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Invalid parameter, with a description of the constraint.
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+    /// Empty action space, i.e. no action can be chosen.
+    #[error("Unable to choose an action from an empty action space")]
+    EmptyActionSpace,
+    /// Space without enumeration, e.g. a continuous space, where enumeration is required.
+    #[error("Unable to enumerate a non-enumerable space")]
+    NonEnumerableSpace,
+    /// Action not in the action space, with its representation.
+    #[error("Unknown action {0}")]
+    UnknownAction(String),
+    /// Agent and environment with different spaces, i.e. `actions` or `states`.
+    #[error("Agent and environment have different {0}-space")]
+    SpaceMismatch(&'static str),
+    /// Error of the results data frame.
+    #[error(transparent)]
+    DataFrame(#[from] PolarsError),
+    /// Thread pool error, e.g. while executing repetitions in parallel.
+    #[error(transparent)]
+    ThreadPool(#[from] rayon::ThreadPoolBuildError),
+    /// Input/output error, e.g. while reading or writing a checkpoint.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// Plotting error, e.g. an unsupported format or a failure of the drawing backend.
+    #[cfg(feature = "plot")]
+    #[error("Unable to plot: {0}")]
+    Plot(String),
+    /// Serialization error, e.g. while parsing a checkpoint.
+    #[cfg(feature = "serde")]
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+}
+
+/// Result of the crate.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,56 @@
+use std::fmt::Debug;
+
+use crate::{
+    spaces::{Bounded, Space},
+    types::Observation,
+};
+
+/// Definition of the features of a state, i.e. a fixed-size real vector for function approximation.
+pub trait Features<S>: Clone + Debug
+where
+    S: Observation,
+{
+    /// Gets the state space.
+    fn state_space(&self) -> &dyn Space<S>;
+
+    /// Gets the number of features.
+    fn len(&self) -> usize;
+
+    /// Checks whether there are no features.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Computes the features of the given state.
+    fn call(&self, state: &S) -> Vec<f64>;
+
+    /// Computes the non-zero features of the given state as (index, value) pairs, e.g. for sparse features.
+    fn call_sparse(&self, state: &S) -> Vec<(usize, f64)> {
+        self.call(state)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, x)| *x != 0.)
+            .collect()
+    }
+}
+
+/// Checks that the given space is bounded, as required to normalize its elements.
+pub(crate) fn assert_bounded(space: &Bounded) {
+    assert!(
+        space.low().iter().chain(space.high()).all(|x| x.is_finite()),
+        "State space must be bounded"
+    );
+}
+
+/// Normalizes an element of a bounded space into the unit hypercube, clipping it to the bounds.
+pub(crate) fn normalize(space: &Bounded, x: &[f64]) -> Vec<f64> {
+    space
+        .clip(x)
+        .into_iter()
+        .zip(space.low().iter().zip(space.high()))
+        .map(|(x, (l, h))| match l < h {
+            false => 0.,
+            true => (x - l) / (h - l),
+        })
+        .collect()
+}
@@ -0,0 +1,55 @@
+use std::f64::consts::PI;
+
+use super::{
+    feature::{assert_bounded, normalize},
+    Features,
+};
+use crate::spaces::{Bounded, MultiDiscrete, Space};
+
+/// Fourier cosine basis over a bounded continuous space, as in Sutton & Barto (Section 9.5.2).
+///
+/// The features are `cos(pi * c * x)` for each integer vector `c` with entries up to the given order,
+/// where `x` is the state normalized into the unit hypercube, hence there are `(order + 1)^k` features
+/// for a `k`-dimensional space.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fourier {
+    states: Bounded,
+    coefficients: Vec<Vec<usize>>,
+}
+
+impl Fourier {
+    /// Constructs the Fourier basis given the bounded state space and the order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state space is not bounded.
+    pub fn new(states: Bounded, order: usize) -> Self {
+        assert_bounded(&states);
+        let coefficients = MultiDiscrete(vec![order + 1; states.low().len()])
+            .iter()
+            .expect("Unable to iterate of enumerable space")
+            .collect();
+
+        Self { states, coefficients }
+    }
+}
+
+impl Features<Vec<f64>> for Fourier {
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.states
+    }
+
+    fn len(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    fn call(&self, state: &Vec<f64>) -> Vec<f64> {
+        let x = normalize(&self.states, state);
+
+        self.coefficients
+            .iter()
+            .map(|c| (PI * x.iter().zip(c).map(|(x, &c)| x * c as f64).sum::<f64>()).cos())
+            .collect()
+    }
+}
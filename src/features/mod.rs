@@ -0,0 +1,14 @@
+mod feature;
+pub use feature::Features;
+
+mod tile_coding;
+pub use tile_coding::TileCoding;
+
+mod radial_basis;
+pub use radial_basis::RadialBasis;
+
+mod polynomial;
+pub use polynomial::Polynomial;
+
+mod fourier;
+pub use fourier::Fourier;
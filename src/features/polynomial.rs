@@ -0,0 +1,52 @@
+use super::{
+    feature::{assert_bounded, normalize},
+    Features,
+};
+use crate::spaces::{Bounded, MultiDiscrete, Space};
+
+/// Polynomial basis over a bounded continuous space, as in Sutton & Barto (Section 9.5.1).
+///
+/// The features are the products of the (normalized) state dimensions, each one raised to a power
+/// up to the given order, hence there are `(order + 1)^k` features for a `k`-dimensional space.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polynomial {
+    states: Bounded,
+    exponents: Vec<Vec<usize>>,
+}
+
+impl Polynomial {
+    /// Constructs the polynomial basis given the bounded state space and the order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state space is not bounded.
+    pub fn new(states: Bounded, order: usize) -> Self {
+        assert_bounded(&states);
+        let exponents = MultiDiscrete(vec![order + 1; states.low().len()])
+            .iter()
+            .expect("Unable to iterate of enumerable space")
+            .collect();
+
+        Self { states, exponents }
+    }
+}
+
+impl Features<Vec<f64>> for Polynomial {
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.states
+    }
+
+    fn len(&self) -> usize {
+        self.exponents.len()
+    }
+
+    fn call(&self, state: &Vec<f64>) -> Vec<f64> {
+        let x = normalize(&self.states, state);
+
+        self.exponents
+            .iter()
+            .map(|c| x.iter().zip(c).map(|(x, &c)| x.powi(c as i32)).product())
+            .collect()
+    }
+}
@@ -0,0 +1,71 @@
+use super::{
+    feature::{assert_bounded, normalize},
+    Features,
+};
+use crate::spaces::{Bounded, MultiDiscrete, Space};
+
+/// Gaussian radial basis functions over a bounded continuous space, as in Sutton & Barto (Section 9.5.5).
+///
+/// The centers lie on a regular grid with the given number of centers per dimension,
+/// where the state is normalized into the unit hypercube before computing its distance to each center.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadialBasis {
+    states: Bounded,
+    centers: Vec<Vec<f64>>,
+    sigma: f64,
+}
+
+impl RadialBasis {
+    /// Constructs the radial basis functions given the bounded state space, the number of centers
+    /// of each dimension and the (normalized) width of the basis functions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state space is not bounded, if the centers and the space have different dimensions,
+    /// or if the number of centers or the width is not positive.
+    pub fn new(states: Bounded, centers: Vec<usize>, sigma: f64) -> Self {
+        assert_bounded(&states);
+        assert_eq!(
+            centers.len(),
+            states.low().len(),
+            "Centers and space must have the same dimension"
+        );
+        assert!(centers.iter().all(|&c| c > 0), "Number of centers must be positive");
+        assert!(sigma > 0., "Width must be positive");
+        // Place the centers evenly on each dimension, including the bounds.
+        let grid = |i: usize, n: usize| match n {
+            1 => 0.5,
+            n => i as f64 / (n - 1) as f64,
+        };
+        let centers = MultiDiscrete(centers.clone())
+            .iter()
+            .expect("Unable to iterate of enumerable space")
+            .map(|c| c.into_iter().zip(&centers).map(|(i, &n)| grid(i, n)).collect())
+            .collect();
+
+        Self { states, centers, sigma }
+    }
+}
+
+impl Features<Vec<f64>> for RadialBasis {
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.states
+    }
+
+    fn len(&self) -> usize {
+        self.centers.len()
+    }
+
+    fn call(&self, state: &Vec<f64>) -> Vec<f64> {
+        let x = normalize(&self.states, state);
+
+        self.centers
+            .iter()
+            .map(|c| {
+                let d: f64 = x.iter().zip(c).map(|(x, c)| (x - c).powi(2)).sum();
+                (-d / (2. * self.sigma.powi(2))).exp()
+            })
+            .collect()
+    }
+}
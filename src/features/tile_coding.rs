@@ -0,0 +1,103 @@
+use super::{
+    feature::{assert_bounded, normalize},
+    Features,
+};
+use crate::spaces::{Bounded, Space};
+
+/// Tile coding of a bounded continuous space, as in Sutton & Barto (Section 9.5.4) and Sutton's `tiles3`.
+///
+/// The space is covered by a number of tilings, each one a grid with the given number of tiles per dimension,
+/// offset from each other by asymmetric displacements. The coordinates of the active tile of each tiling are
+/// hashed into a table of given size, as `tiles3` with an integer size, so that memory is bounded regardless
+/// of the number of tiles, at the cost of (rare) collisions. The features are binary, one for each active tile.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TileCoding {
+    states: Bounded,
+    tiles: Vec<usize>,
+    tilings: usize,
+    size: usize,
+}
+
+impl TileCoding {
+    /// Offset basis of the (64-bit) FNV-1a hash, which is stable across platforms and runs.
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    /// Prime of the (64-bit) FNV-1a hash.
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    /// Constructs a tile coding given the bounded state space, the number of tiles of each dimension,
+    /// the number of tilings and the size of the hash table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state space is not bounded, if the tiles and the space have different dimensions
+    /// or if the number of tiles, the number of tilings or the size is not positive.
+    pub fn new(states: Bounded, tiles: Vec<usize>, tilings: usize, size: usize) -> Self {
+        assert_bounded(&states);
+        assert_eq!(
+            tiles.len(),
+            states.low().len(),
+            "Tiles and space must have the same dimension"
+        );
+        assert!(tiles.iter().all(|&t| t > 0), "Number of tiles must be positive");
+        assert!(tilings > 0, "Number of tilings must be positive");
+        assert!(size > 0, "Size must be positive");
+
+        Self {
+            states,
+            tiles,
+            tilings,
+            size,
+        }
+    }
+
+    /// Computes the indices of the active tiles of the given state, one for each tiling.
+    pub fn indices(&self, state: &[f64]) -> Vec<usize> {
+        let n = self.tilings as i64;
+        // Quantize the state in units of tile width divided by the number of tilings.
+        let q: Vec<i64> = normalize(&self.states, state)
+            .into_iter()
+            .zip(&self.tiles)
+            .map(|(x, &t)| (x * t as f64 * n as f64).floor() as i64)
+            .collect();
+
+        (0..n)
+            .map(|tiling| {
+                // Offset each dimension by an odd multiple of the tiling index, then hash the coordinates.
+                let coordinates = q
+                    .iter()
+                    .enumerate()
+                    .map(|(i, q)| (q + tiling * (2 * i as i64 + 1)).div_euclid(n));
+                let hash = std::iter::once(tiling)
+                    .chain(coordinates)
+                    .flat_map(i64::to_le_bytes)
+                    .fold(Self::FNV_OFFSET, |h, b| (h ^ b as u64).wrapping_mul(Self::FNV_PRIME));
+
+                (hash % self.size as u64) as usize
+            })
+            .collect()
+    }
+}
+
+impl Features<Vec<f64>> for TileCoding {
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.states
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    fn call(&self, state: &Vec<f64>) -> Vec<f64> {
+        let mut x = vec![0.; self.size];
+        for i in self.indices(state) {
+            x[i] += 1.;
+        }
+
+        x
+    }
+
+    fn call_sparse(&self, state: &Vec<f64>) -> Vec<(usize, f64)> {
+        self.indices(state).into_iter().map(|i| (i, 1.)).collect()
+    }
+}
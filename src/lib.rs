@@ -3,6 +3,9 @@
 
 //! A REInforcement Learning LibrarY.
 
+mod error;
+pub use error::{Error, Result};
+
 /// Generic reinforcement learning agents.
 pub mod agents;
 
@@ -12,11 +15,30 @@ pub mod envs;
 /// Generic agent-environment experiment sessions.
 pub mod sessions;
 
+/// Summary statistics and significance tests of experiment session results.
+pub mod results;
+
+/// Plots of experiment session results.
+#[cfg(feature = "plot")]
+pub mod plot;
+
+/// Collection of action and state spaces.
+pub mod spaces;
+
 /// Collection of agent's policies.
 pub mod policies;
 
 /// Collection of value functions.
 pub mod values;
 
+/// Collection of state features for function approximation.
+pub mod features;
+
+/// Collection of neural networks for function approximation.
+pub mod networks;
+
+/// Collection of experience replay memories.
+pub mod replays;
+
 /// Definition of fundamental types.
 pub mod types;
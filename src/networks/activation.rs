@@ -0,0 +1,35 @@
+/// Activation function of the hidden layers of a network.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activation {
+    /// Identity function, i.e. no activation.
+    Identity,
+    /// Rectified linear unit, i.e. `max(0, x)`.
+    #[default]
+    ReLU,
+    /// Hyperbolic tangent.
+    Tanh,
+}
+
+impl Activation {
+    /// Computes the activation of the given input.
+    pub fn call(&self, x: f64) -> f64 {
+        match self {
+            Self::Identity => x,
+            Self::ReLU => x.max(0.),
+            Self::Tanh => x.tanh(),
+        }
+    }
+
+    /// Computes the derivative of the activation given its output, which determines it for each function.
+    pub fn derivative(&self, y: f64) -> f64 {
+        match self {
+            Self::Identity => 1.,
+            Self::ReLU => match y > 0. {
+                false => 0.,
+                true => 1.,
+            },
+            Self::Tanh => 1. - y.powi(2),
+        }
+    }
+}
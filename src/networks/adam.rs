@@ -0,0 +1,92 @@
+use super::Optimizer;
+
+/// Adam optimizer, as in Kingma & Ba (2015).
+///
+/// The step of each parameter is its step size scaled by the ratio of the bias-corrected
+/// estimates of the first and second moment of its gradient.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adam {
+    alpha: f64,
+    beta_1: f64,
+    beta_2: f64,
+    epsilon: f64,
+    m: Vec<f64>,
+    v: Vec<f64>,
+    count: i32,
+}
+
+impl Adam {
+    /// Constructs an Adam optimizer given the step size, with the default decay rates of the moments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step size is not positive.
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0., "Step size must be positive");
+
+        Self {
+            alpha,
+            beta_1: 0.9,
+            beta_2: 0.999,
+            epsilon: 1e-8,
+            m: vec![],
+            v: vec![],
+            count: 0,
+        }
+    }
+
+    /// Sets the decay rates of the first and second moment estimates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decay rates are not in `[0, 1)`.
+    pub fn with_decay(mut self, beta_1: f64, beta_2: f64) -> Self {
+        assert!(
+            (0. ..1.).contains(&beta_1) && (0. ..1.).contains(&beta_2),
+            "Decay rates must be in [0, 1)"
+        );
+        self.beta_1 = beta_1;
+        self.beta_2 = beta_2;
+
+        self
+    }
+}
+
+impl Default for Adam {
+    fn default() -> Self {
+        Self::new(1e-3)
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self, gradient: &[f64]) -> Vec<f64> {
+        // Initialize the moments on the first step.
+        if self.m.len() != gradient.len() {
+            self.m = vec![0.; gradient.len()];
+            self.v = vec![0.; gradient.len()];
+            self.count = 0;
+        }
+        self.count = self.count.saturating_add(1);
+        // Correct the bias of the moments, which are initialized to zero.
+        let (c_1, c_2) = (1. - self.beta_1.powi(self.count), 1. - self.beta_2.powi(self.count));
+
+        self.m
+            .iter_mut()
+            .zip(self.v.iter_mut())
+            .zip(gradient)
+            .map(|((m, v), g)| {
+                *m = self.beta_1 * *m + (1. - self.beta_1) * g;
+                *v = self.beta_2 * *v + (1. - self.beta_2) * g.powi(2);
+
+                -self.alpha * (*m / c_1) / ((*v / c_2).sqrt() + self.epsilon)
+            })
+            .collect()
+    }
+
+    fn reset(&mut self) {
+        self.m.clear();
+        self.v.clear();
+        self.count = 0;
+    }
+}
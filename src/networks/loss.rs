@@ -0,0 +1,41 @@
+/// Loss function between the prediction of a network and its target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Loss {
+    /// Squared error, halved so that its derivative is the error.
+    Squared,
+    /// Huber loss with the given threshold, i.e. squared for small errors and linear for large ones,
+    /// which bounds the gradient as the error clipping of Mnih et al. (2015).
+    Huber(f64),
+}
+
+impl Default for Loss {
+    fn default() -> Self {
+        Self::Huber(1.)
+    }
+}
+
+impl Loss {
+    /// Computes the loss of the given prediction and target.
+    pub fn call(&self, prediction: f64, target: f64) -> f64 {
+        let e = prediction - target;
+
+        match *self {
+            Self::Squared => e.powi(2) / 2.,
+            Self::Huber(delta) => match e.abs() <= delta {
+                false => delta * (e.abs() - delta / 2.),
+                true => e.powi(2) / 2.,
+            },
+        }
+    }
+
+    /// Computes the derivative of the loss with respect to the prediction.
+    pub fn derivative(&self, prediction: f64, target: f64) -> f64 {
+        let e = prediction - target;
+
+        match *self {
+            Self::Squared => e,
+            Self::Huber(delta) => e.clamp(-delta, delta),
+        }
+    }
+}
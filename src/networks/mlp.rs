@@ -0,0 +1,187 @@
+use rand::Rng;
+use rand_distr::{Distribution, Uniform};
+
+use super::Activation;
+
+/// Multi-layer perceptron, i.e. a fully-connected feed-forward network, on the CPU.
+///
+/// Each layer is an affine map followed by the activation, except for the output layer, which is linear.
+/// The parameters are stored in a single vector, layer by layer, as the row-major weights followed by
+/// the biases, so that optimizers step over them as a whole.
+///
+/// The initialization is fixed at construction: resetting the network restores the same initial parameters,
+/// hence every repetition of a session starts from identical weights, e.g. clones of the same agent.
+/// To repeat an experiment over different initializations, construct a network for each initialization
+/// with its own random number generator, then run a session with a single repetition on each of them.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mlp {
+    sizes: Vec<usize>,
+    activation: Activation,
+    theta_0: Vec<f64>,
+    theta: Vec<f64>,
+}
+
+impl Mlp {
+    /// Constructs a network given the sizes of its layers, from the inputs to the outputs,
+    /// and the activation of the hidden layers.
+    ///
+    /// The weights are initialized with the given random number generator, uniformly scaled by the
+    /// fan-in for ReLU, as in He et al. (2015), or by the fan-in and fan-out otherwise,
+    /// as in Glorot & Bengio (2010), while the biases are initialized to zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than two layers or if any layer is empty.
+    pub fn new<T>(sizes: Vec<usize>, activation: Activation, rng: &mut T) -> Self
+    where
+        T: Rng + ?Sized,
+    {
+        assert!(
+            sizes.len() >= 2,
+            "Network must have at least an input and an output layer"
+        );
+        assert!(sizes.iter().all(|&n| n > 0), "Layers must be non-empty");
+
+        let mut theta = Vec::new();
+        for (l, w) in sizes.windows(2).enumerate() {
+            let (n, m) = (w[0], w[1]);
+            // The output layer is linear, hence scaled as such.
+            let limit = match (activation, l + 2 < sizes.len()) {
+                (Activation::ReLU, true) => (6. / n as f64).sqrt(),
+                _ => (6. / (n + m) as f64).sqrt(),
+            };
+            let uniform = Uniform::new_inclusive(-limit, limit);
+            theta.extend((0..n * m).map(|_| uniform.sample(rng)));
+            theta.extend(vec![0.; m]);
+        }
+
+        Self {
+            sizes,
+            activation,
+            theta_0: theta.clone(),
+            theta,
+        }
+    }
+
+    /// Gets the size of the inputs.
+    pub fn inputs(&self) -> usize {
+        self.sizes[0]
+    }
+
+    /// Gets the size of the outputs.
+    pub fn outputs(&self) -> usize {
+        self.sizes[self.sizes.len() - 1]
+    }
+
+    /// Gets the parameters of the network.
+    pub fn parameters(&self) -> &[f64] {
+        &self.theta
+    }
+
+    /// Computes the outputs of the network given its inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inputs do not match the size of the input layer.
+    pub fn call(&self, x: &[f64]) -> Vec<f64> {
+        self.forward(x).pop().expect("Unable to get the outputs of the network")
+    }
+
+    /// Computes the gradient of a loss with respect to the parameters, given the inputs and
+    /// the gradient of the loss with respect to the outputs, by backpropagation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inputs or the gradient do not match the size of the input or output layer.
+    pub fn gradient(&self, x: &[f64], dy: &[f64]) -> Vec<f64> {
+        assert_eq!(
+            dy.len(),
+            self.outputs(),
+            "Gradient and outputs must have the same dimension"
+        );
+
+        let ys = self.forward(x);
+        let mut grad = vec![0.; self.theta.len()];
+        let mut delta = dy.to_vec();
+        // Walk the layers backwards, from the end of the parameters.
+        let mut offset = self.theta.len();
+        for l in (0..self.sizes.len() - 1).rev() {
+            let (n, m) = (self.sizes[l], self.sizes[l + 1]);
+            offset -= n * m + m;
+            // Backpropagate through the activation, except for the output layer ...
+            if l + 2 < self.sizes.len() {
+                for (d, y) in delta.iter_mut().zip(&ys[l + 1]) {
+                    *d *= self.activation.derivative(*y);
+                }
+            }
+            // ... then accumulate the gradient of the weights and biases ...
+            let (w, b) = grad[offset..offset + n * m + m].split_at_mut(n * m);
+            for (i, d) in delta.iter().enumerate() {
+                for (g, x) in w[i * n..(i + 1) * n].iter_mut().zip(&ys[l]) {
+                    *g = d * x;
+                }
+                b[i] = *d;
+            }
+            // ... and the gradient of the inputs of the layer.
+            let w = &self.theta[offset..offset + n * m];
+            delta = (0..n)
+                .map(|j| delta.iter().enumerate().map(|(i, d)| d * w[i * n + j]).sum())
+                .collect();
+        }
+
+        grad
+    }
+
+    /// Updates the parameters given a step, e.g. computed by an [`Optimizer`](super::Optimizer).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step does not match the number of parameters.
+    pub fn update(&mut self, step: &[f64]) {
+        assert_eq!(
+            step.len(),
+            self.theta.len(),
+            "Step and parameters must have the same dimension"
+        );
+
+        self.theta.iter_mut().zip(step).for_each(|(x, s)| *x += s);
+    }
+
+    /// Resets the parameters to their initial values, i.e. the ones drawn at construction.
+    pub fn reset(&mut self) {
+        self.theta.clone_from(&self.theta_0);
+    }
+
+    /// Computes the outputs of each layer, from the inputs to the outputs.
+    fn forward(&self, x: &[f64]) -> Vec<Vec<f64>> {
+        assert_eq!(
+            x.len(),
+            self.inputs(),
+            "Inputs and network must have the same dimension"
+        );
+
+        let mut ys = vec![x.to_vec()];
+        let mut offset = 0;
+        for l in 0..self.sizes.len() - 1 {
+            let (n, m) = (self.sizes[l], self.sizes[l + 1]);
+            let (w, b) = self.theta[offset..offset + n * m + m].split_at(n * m);
+            let is_hidden = l + 2 < self.sizes.len();
+            let y = w
+                .chunks(n)
+                .zip(b)
+                .map(|(w, b)| {
+                    let z = w.iter().zip(&ys[l]).map(|(w, x)| w * x).sum::<f64>() + b;
+                    match is_hidden {
+                        false => z,
+                        true => self.activation.call(z),
+                    }
+                })
+                .collect();
+            ys.push(y);
+            offset += n * m + m;
+        }
+
+        ys
+    }
+}
@@ -0,0 +1,17 @@
+mod activation;
+pub use activation::Activation;
+
+mod loss;
+pub use loss::Loss;
+
+mod mlp;
+pub use mlp::Mlp;
+
+mod optimizer;
+pub use optimizer::Optimizer;
+
+mod sgd;
+pub use sgd::Sgd;
+
+mod adam;
+pub use adam::Adam;
@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+/// Definition of a gradient-based optimizer of the parameters of a network.
+pub trait Optimizer: Clone + Debug {
+    /// Computes the step of the parameters given the gradient of the loss, i.e. a descent step
+    /// to be added to the parameters.
+    fn step(&mut self, gradient: &[f64]) -> Vec<f64>;
+
+    /// Resets the optimizer, e.g. its moment estimates.
+    fn reset(&mut self);
+}
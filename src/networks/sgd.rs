@@ -0,0 +1,58 @@
+use super::Optimizer;
+
+/// Stochastic gradient descent optimizer, with optional (heavy-ball) momentum.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sgd {
+    alpha: f64,
+    momentum: f64,
+    velocity: Vec<f64>,
+}
+
+impl Sgd {
+    /// Constructs a gradient descent optimizer given the step size, without momentum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step size is not positive.
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0., "Step size must be positive");
+
+        Self {
+            alpha,
+            momentum: 0.,
+            velocity: vec![],
+        }
+    }
+
+    /// Sets the momentum of the steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the momentum is not in `[0, 1)`.
+    pub fn with_momentum(mut self, momentum: f64) -> Self {
+        assert!((0. ..1.).contains(&momentum), "Momentum must be in [0, 1)");
+        self.momentum = momentum;
+
+        self
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&mut self, gradient: &[f64]) -> Vec<f64> {
+        // Initialize the velocity on the first step.
+        if self.velocity.len() != gradient.len() {
+            self.velocity = vec![0.; gradient.len()];
+        }
+        // Accumulate the velocity, then step along it.
+        for (v, g) in self.velocity.iter_mut().zip(gradient) {
+            *v = self.momentum * *v - self.alpha * g;
+        }
+
+        self.velocity.clone()
+    }
+
+    fn reset(&mut self) {
+        self.velocity.clear();
+    }
+}
@@ -0,0 +1,301 @@
+use std::{collections::HashMap, path::Path};
+
+use plotters::{coord::Shift, prelude::*};
+use polars::prelude::*;
+use rand::Rng;
+
+use crate::{
+    results::{Interval, Summary},
+    Error, Result,
+};
+
+/// Metric plotted by a figure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// Learning curve, i.e. the mean `reward`.
+    Reward,
+    /// Cumulative pseudo-regret, i.e. the `regret` summed over each repetition.
+    CumulativeRegret,
+    /// Percent of optimal actions, i.e. the mean `optimal` times 100.
+    Optimal,
+}
+
+impl Metric {
+    /// Gets the label of the metric.
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Reward => "reward",
+            Self::CumulativeRegret => "cumulative regret",
+            Self::Optimal => "% optimal action",
+        }
+    }
+}
+
+/// Curve of a series, i.e. the x values with the mean and the confidence band of the metric.
+type Curve = (String, Vec<(f64, f64, f64, f64)>);
+
+/// A figure of session results, plotting a metric against a column, e.g. `episode` or `step`.
+///
+/// The metric is averaged across repetitions, i.e. the `reps` column, drawing its mean as a line
+/// within its confidence band. If a series column is set, e.g. the `agent` column of a benchmark,
+/// one line for each of its values is drawn. The file format is given by the extension of the path,
+/// where `svg` is supported by the `plot` feature and `png` by the `plot-png` feature, which requires
+/// a font to be registered, see `register_font`.
+#[derive(Clone, Debug)]
+pub struct Figure {
+    x: String,
+    metric: Metric,
+    series: Option<String>,
+    interval: Interval,
+    title: String,
+    size: (u32, u32),
+}
+
+impl Figure {
+    /// Constructs a figure of the given metric against the given column.
+    pub fn new<S>(x: S, metric: Metric) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            x: x.into(),
+            metric,
+            series: None,
+            interval: Default::default(),
+            title: String::new(),
+            size: (800, 600),
+        }
+    }
+
+    /// Constructs a learning curve figure against the given column.
+    pub fn learning_curve<S>(x: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(x, Metric::Reward)
+    }
+
+    /// Constructs a cumulative regret figure against the given column.
+    pub fn cumulative_regret<S>(x: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(x, Metric::CumulativeRegret)
+    }
+
+    /// Constructs a percent of optimal actions figure against the given column.
+    pub fn optimal_actions<S>(x: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(x, Metric::Optimal)
+    }
+
+    /// Sets the series column, drawing one line for each of its values.
+    pub fn with_series<S>(mut self, series: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.series = Some(series.into());
+
+        self
+    }
+
+    /// Sets the confidence band of the mean.
+    pub fn with_interval(mut self, interval: Interval) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Sets the title.
+    pub fn with_title<S>(mut self, title: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = title.into();
+
+        self
+    }
+
+    /// Sets the width and height, in pixels.
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.size = (width, height);
+
+        self
+    }
+
+    /// Computes the curve of each series, failing if the required columns are missing or invalid.
+    fn curves<T>(&self, data: &DataFrame, rng: &mut T) -> Result<Vec<Curve>>
+    where
+        T: Rng + ?Sized,
+    {
+        let mut data = data.clone();
+        // Cast the series to strings, if any.
+        let series = match &self.series {
+            Some(series) => data.column(series)?.cast(&DataType::Utf8)?,
+            None => Series::new("", vec![""; data.height()]),
+        };
+        let series: Vec<_> = series.utf8()?.into_iter().collect();
+        // Compute the metric.
+        let mut y = match self.metric {
+            Metric::Reward => data.column("reward")?.clone(),
+            Metric::Optimal => data.column("optimal")?.cast(&DataType::Float64)? * 100.,
+            Metric::CumulativeRegret => {
+                let reps = data.column("reps")?.cast(&DataType::UInt64)?;
+                let reps = reps.u64()?;
+                let regret = data.column("regret")?.cast(&DataType::Float64)?;
+                let regret = regret.f64()?;
+                // Sum the regret over the rows of each repetition of each series.
+                let mut sums = HashMap::new();
+                let y: Vec<_> = series
+                    .iter()
+                    .zip(reps)
+                    .zip(regret)
+                    .map(|((s, r), g)| {
+                        let sum = sums.entry((*s, r)).or_insert(0.);
+                        *sum += g.unwrap_or_default();
+                        *sum
+                    })
+                    .collect();
+                Series::new("", y)
+            }
+        };
+        y.rename("y");
+        data.with_column(y)?;
+        data.with_column(Series::new("series", series))?;
+        // Summarize the metric of each series at each x value.
+        let summary = Summary::new(["series", self.x.as_str()], ["y"])
+            .with_quantiles([])
+            .with_interval(self.interval)
+            .try_call(&data, rng)?;
+        let column = |name: &str| -> Result<Vec<f64>> {
+            let column = summary.column(name)?.cast(&DataType::Float64)?;
+
+            Ok(column.f64()?.into_iter().map(|c| c.unwrap_or(f64::NAN)).collect())
+        };
+        let (x, mean, lower, upper) = (
+            column(&self.x)?,
+            column("y_mean")?,
+            column("y_lower")?,
+            column("y_upper")?,
+        );
+        let series = summary.column("series")?.utf8()?;
+        // Collect the points of each series, in order of first occurrence.
+        let mut curves: Vec<Curve> = vec![];
+        for (i, s) in series.into_iter().enumerate() {
+            let s = s.unwrap_or_default();
+            // Collapse undefined bands to the mean, e.g. for a single repetition.
+            let (l, u) = match lower[i].is_finite() && upper[i].is_finite() {
+                false => (mean[i], mean[i]),
+                true => (lower[i], upper[i]),
+            };
+            let point = (x[i], mean[i], l, u);
+            match curves.iter_mut().find(|(name, _)| name == s) {
+                Some((_, points)) => points.push(point),
+                None => curves.push((s.to_string(), vec![point])),
+            }
+        }
+        // Sort the points of each curve by x value.
+        curves
+            .iter_mut()
+            .for_each(|(_, points)| points.sort_by(|a, b| a.0.total_cmp(&b.0)));
+
+        Ok(curves)
+    }
+
+    /// Draws the curves on the given drawing area.
+    fn draw<B>(
+        &self,
+        root: DrawingArea<B, Shift>,
+        curves: &[Curve],
+    ) -> std::result::Result<(), Box<dyn std::error::Error>>
+    where
+        B: DrawingBackend,
+        B::ErrorType: 'static,
+    {
+        // Compute the bounds of the axes.
+        let points = curves
+            .iter()
+            .flat_map(|(_, p)| p)
+            .filter(|p| p.0.is_finite() && p.1.is_finite());
+        let (x_min, x_max, y_min, y_max) = points.fold(
+            (f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY),
+            |(a, b, c, d), &(x, _, l, u)| (a.min(x), b.max(x), c.min(l), d.max(u)),
+        );
+        let (x_min, x_max, y_min, y_max) = match x_min <= x_max && y_min <= y_max {
+            false => (0., 1., 0., 1.),
+            true => (x_min, x_max.max(x_min + 1.), y_min, y_max.max(y_min + 1e-9)),
+        };
+        let margin = (y_max - y_min) * 0.05;
+
+        root.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&root)
+            .caption(&self.title, ("sans-serif", 24))
+            .margin(16)
+            .x_label_area_size(40)
+            .y_label_area_size(60)
+            .build_cartesian_2d(x_min..x_max, (y_min - margin)..(y_max + margin))?;
+        chart
+            .configure_mesh()
+            .x_desc(self.x.as_str())
+            .y_desc(self.metric.label())
+            .draw()?;
+        // Draw the confidence band, then the mean of each curve.
+        for (i, (name, points)) in curves.iter().enumerate() {
+            let color = Palette99::pick(i).to_rgba();
+            let band: Vec<_> = points
+                .iter()
+                .map(|p| (p.0, p.3))
+                .chain(points.iter().rev().map(|p| (p.0, p.2)))
+                .collect();
+            chart.draw_series(std::iter::once(Polygon::new(band, color.mix(0.2).filled())))?;
+            let line = chart.draw_series(LineSeries::new(
+                points.iter().map(|p| (p.0, p.1)),
+                color.stroke_width(2),
+            ))?;
+            if self.series.is_some() {
+                line.label(name.as_str())
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
+            }
+        }
+        // Draw the legend, if any.
+        if self.series.is_some() {
+            chart
+                .configure_series_labels()
+                .background_style(WHITE.mix(0.8))
+                .border_style(BLACK)
+                .draw()?;
+        }
+        root.present()?;
+
+        Ok(())
+    }
+
+    /// Plots the session results to the given path,
+    /// failing if the required columns are missing, the format is unsupported or the drawing fails.
+    pub fn call<P, T>(&self, data: &DataFrame, path: P, rng: &mut T) -> Result<()>
+    where
+        P: AsRef<Path>,
+        T: Rng + ?Sized,
+    {
+        let path = path.as_ref();
+        let curves = self.curves(data, rng)?;
+        // Select the backend given the extension of the path, then draw the curves.
+        let drawn = match path.extension().and_then(|e| e.to_str()) {
+            Some("svg") => self.draw(SVGBackend::new(path, self.size).into_drawing_area(), &curves),
+            #[cfg(feature = "plot-png")]
+            Some("png") => self.draw(BitMapBackend::new(path, self.size).into_drawing_area(), &curves),
+            _ => return Err(Error::Plot(format!("Unsupported format of {:?}", path))),
+        };
+
+        drawn.map_err(|e| Error::Plot(e.to_string()))
+    }
+}
+
+/// Registers the font used to render the text of the figures, given the bytes of a TrueType font.
+#[cfg(feature = "plot-png")]
+pub fn register_font(bytes: &'static [u8]) -> Result<()> {
+    plotters::style::register_font("sans-serif", FontStyle::Normal, bytes)
+        .map_err(|_| Error::Plot("Invalid font".into()))
+}
@@ -0,0 +1,4 @@
+mod figure;
+#[cfg(feature = "plot-png")]
+pub use figure::register_font;
+pub use figure::{Figure, Metric};
@@ -5,28 +5,71 @@ use rand_distr::Uniform;
 
 use super::{Greedy, Policy, Random};
 use crate::{
-    types::{Action, Reward, State},
+    types::{Action, Observation, Reward},
     values::StateActionValue,
+    Error, Result,
 };
 
 /// Epsilon-greedy policy.
+///
+/// Epsilon is either constant or, if a schedule is set, linearly decayed to its final value
+/// over a number of steps, see [`Policy::step`].
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EpsilonGreedy {
     epsilon_0: f64,
     epsilon: f64,
+    schedule: Option<(f64, usize)>,
+    count: usize,
     greedy: Greedy,
     random: Random,
 }
 
 impl EpsilonGreedy {
-    /// Constructs a random policy.
+    /// Constructs an epsilon-greedy policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if epsilon is not a probability, see [`EpsilonGreedy::try_new`].
     pub fn new(epsilon: f64) -> Self {
-        Self {
+        Self::try_new(epsilon).expect("Unable to construct epsilon-greedy policy")
+    }
+
+    /// Constructs an epsilon-greedy policy, failing if epsilon is not a probability.
+    pub fn try_new(epsilon: f64) -> Result<Self> {
+        // Check epsilon is in [0, 1].
+        if !(0. ..=1.).contains(&epsilon) {
+            return Err(Error::InvalidParameter(format!(
+                "Epsilon must be in [0, 1], got {}",
+                epsilon
+            )));
+        }
+
+        Ok(Self {
             epsilon_0: epsilon,
             epsilon,
+            schedule: None,
+            count: 0,
             greedy: Default::default(),
             random: Default::default(),
-        }
+        })
+    }
+
+    /// Sets the linear decay of epsilon to its final value over the given number of steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the final epsilon is not a probability.
+    pub fn with_decay(mut self, epsilon: f64, steps: usize) -> Self {
+        assert!((0. ..=1.).contains(&epsilon), "Epsilon must be in [0, 1]");
+        self.schedule = Some((epsilon, steps));
+
+        self
+    }
+
+    /// Gets the current epsilon.
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
     }
 }
 
@@ -37,11 +80,11 @@ impl Default for EpsilonGreedy {
 }
 
 impl Policy for EpsilonGreedy {
-    fn call<A, R, S, V, T>(&self, f: &V, state: &S, rng: &mut T) -> A
+    fn try_call<A, R, S, V, T>(&self, f: &V, state: &S, rng: &mut T) -> Result<A>
     where
         A: Action,
         R: Reward,
-        S: State,
+        S: Observation,
         V: StateActionValue<A, R, S>,
         T: Rng + ?Sized,
     {
@@ -50,17 +93,30 @@ impl Policy for EpsilonGreedy {
         // With probability (1 - epsilon) ...
         match p < (1. - self.epsilon) {
             // ... select an action greedily, otherwise ...
-            false => self.greedy.call(f, state, rng),
+            true => self.greedy.try_call(f, state, rng),
             // ... select a random action form the action space.
-            true => self.random.call(f, state, rng),
+            false => self.random.try_call(f, state, rng),
         }
     }
 
     fn reset(&mut self) {
-        // Reset epsilon.
+        // Reset epsilon and its schedule.
         self.epsilon = self.epsilon_0;
+        self.count = 0;
         // Reset helper policies.
         self.greedy.reset();
         self.random.reset();
     }
+
+    fn step(&mut self) {
+        // Interpolate epsilon between its initial and final value, if scheduled.
+        if let Some((epsilon, steps)) = self.schedule {
+            self.count = steps.min(self.count + 1);
+            let t = match steps {
+                0 => 1.,
+                _ => self.count as f64 / steps as f64,
+            };
+            self.epsilon = self.epsilon_0 + t * (epsilon - self.epsilon_0);
+        }
+    }
 }
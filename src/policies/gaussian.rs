@@ -0,0 +1,203 @@
+use rand::Rng;
+use rand_distr::{Distribution, StandardNormal};
+
+use super::ParametricPolicy;
+use crate::spaces::{Bounded, Space};
+
+/// Gaussian policy over a continuous action space, given a continuous state space.
+///
+/// The mean of each action dimension is linear in the state (with bias), while its standard
+/// deviation is a state-independent parameter, learned in log-space. If squashed, samples are
+/// mapped into the (finite) bounds of the action space with a hyperbolic tangent, otherwise they
+/// are left unbounded and the environment is expected to clip them to the action space.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gaussian {
+    actions: Bounded,
+    states: Bounded,
+    std_0: f64,
+    is_squashed: bool,
+    theta: Vec<f64>,
+}
+
+impl Gaussian {
+    /// Bounds of the logarithm of the standard deviation, preventing its collapse or explosion.
+    const LOG_STD: (f64, f64) = (-5., 2.);
+
+    /// Constructs a Gaussian policy given the action space and the state space,
+    /// with zero mean and unit standard deviation.
+    pub fn new(actions: Bounded, states: Bounded) -> Self {
+        let mut pi = Self {
+            actions,
+            states,
+            std_0: 1.,
+            is_squashed: false,
+            theta: vec![],
+        };
+        pi.reset();
+
+        pi
+    }
+
+    /// Sets the initial standard deviation of each action dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the standard deviation is not positive.
+    pub fn with_std(mut self, std: f64) -> Self {
+        assert!(std > 0., "Standard deviation must be positive");
+        self.std_0 = std;
+        self.reset();
+
+        self
+    }
+
+    /// Sets whether the samples are squashed into the bounds of the action space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if squashed and the action space is not bounded.
+    pub fn with_squashing(mut self, is_squashed: bool) -> Self {
+        assert!(
+            !is_squashed
+                || self
+                    .actions
+                    .low()
+                    .iter()
+                    .chain(self.actions.high())
+                    .all(|x| x.is_finite()),
+            "Squashing requires a bounded action space"
+        );
+        self.is_squashed = is_squashed;
+
+        self
+    }
+
+    /// Gets the standard deviation of each action dimension.
+    pub fn std(&self) -> Vec<f64> {
+        self.theta[self.offset()..].iter().map(|x| x.exp()).collect()
+    }
+
+    /// Gets the offset of the log-standard deviations in the parameters,
+    /// after the weights of the mean of each action dimension.
+    fn offset(&self) -> usize {
+        self.actions.low().len() * (self.states.low().len() + 1)
+    }
+
+    /// Computes the (unsquashed) mean of each action dimension.
+    fn mean(&self, state: &[f64]) -> Vec<f64> {
+        assert_eq!(
+            state.len(),
+            self.states.low().len(),
+            "State and space must have the same dimension"
+        );
+
+        self.theta[..self.offset()]
+            .chunks(state.len() + 1)
+            .map(|w| w.iter().zip(state.iter().chain([&1.])).map(|(w, x)| w * x).sum())
+            .collect()
+    }
+
+    /// Maps an unbounded sample into the action space, if squashed.
+    fn squash(&self, u: Vec<f64>) -> Vec<f64> {
+        if !self.is_squashed {
+            return u;
+        }
+
+        u.into_iter()
+            .zip(self.actions.low().iter().zip(self.actions.high()))
+            .map(|(u, (l, h))| l + (h - l) * (u.tanh() + 1.) / 2.)
+            .collect()
+    }
+
+    /// Maps an action back to its unbounded sample, if squashed.
+    fn unsquash(&self, action: &[f64]) -> Vec<f64> {
+        if !self.is_squashed {
+            return action.to_vec();
+        }
+
+        action
+            .iter()
+            .zip(self.actions.low().iter().zip(self.actions.high()))
+            // Keep the action strictly inside the bounds, where the inverse is finite.
+            .map(|(a, (l, h))| ((a - l) / (h - l) * 2. - 1.).clamp(-1. + 1e-6, 1. - 1e-6).atanh())
+            .collect()
+    }
+}
+
+impl ParametricPolicy<Vec<f64>, Vec<f64>> for Gaussian {
+    fn action_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.states
+    }
+
+    fn parameters(&self) -> &[f64] {
+        &self.theta
+    }
+
+    fn call<T>(&self, state: &Vec<f64>, rng: &mut T) -> Vec<f64>
+    where
+        T: Rng + ?Sized,
+    {
+        // Sample each action dimension independently ...
+        let u = self
+            .mean(state)
+            .into_iter()
+            .zip(self.std())
+            .map(|(mu, sigma)| {
+                let z: f64 = StandardNormal.sample(rng);
+                mu + sigma * z
+            })
+            .collect();
+        // ... then squash it, if required.
+        self.squash(u)
+    }
+
+    fn mode(&self, state: &Vec<f64>) -> Vec<f64> {
+        self.squash(self.mean(state))
+    }
+
+    fn grad_log_prob(&self, action: &Vec<f64>, state: &Vec<f64>) -> Vec<f64> {
+        // The log-determinant of the squashing does not depend on the parameters,
+        // hence the gradient is the one of the unsquashed sample.
+        let u = self.unsquash(action);
+        let (mut grad, offset) = (vec![0.; self.theta.len()], self.offset());
+        for (j, ((u, mu), sigma)) in u.into_iter().zip(self.mean(state)).zip(self.std()).enumerate() {
+            let z = (u - mu) / sigma;
+            // Gradient with respect to the weights of the mean ...
+            let w = &mut grad[j * (state.len() + 1)..(j + 1) * (state.len() + 1)];
+            for (g, x) in w.iter_mut().zip(state.iter().chain([&1.])) {
+                *g = z / sigma * x;
+            }
+            // ... and to the log-standard deviation.
+            grad[offset + j] = z.powi(2) - 1.;
+        }
+
+        grad
+    }
+
+    fn update(&mut self, step: &[f64]) {
+        assert_eq!(
+            step.len(),
+            self.theta.len(),
+            "Step and parameters must have the same dimension"
+        );
+
+        self.theta.iter_mut().zip(step).for_each(|(x, s)| *x += s);
+        // Bound the log-standard deviations.
+        let offset = self.offset();
+        self.theta[offset..]
+            .iter_mut()
+            .for_each(|x| *x = x.clamp(Self::LOG_STD.0, Self::LOG_STD.1));
+    }
+
+    fn reset(&mut self) {
+        // Reset the weights of the mean to zero and the log-standard deviations to their initial value.
+        let (offset, m) = (self.offset(), self.actions.low().len());
+        self.theta = vec![0.; offset];
+        self.theta.extend(vec![self.std_0.ln(); m]);
+    }
+}
@@ -2,27 +2,34 @@ use rand::Rng;
 
 use super::Policy;
 use crate::{
-    types::{Action, Reward, State},
+    types::{Action, Observation, Reward},
     values::StateActionValue,
+    Error, Result,
 };
 
 /// Greedy policy.
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Greedy {}
 
 impl Policy for Greedy {
-    fn call<A, R, S, V, T>(&self, f: &V, state: &S, _rng: &mut T) -> A
+    fn try_call<A, R, S, V, T>(&self, f: &V, state: &S, _rng: &mut T) -> Result<A>
     where
         A: Action,
         R: Reward,
-        S: State,
+        S: Observation,
         V: StateActionValue<A, R, S>,
         T: Rng + ?Sized,
     {
-        // For each action ...
-        f.actions_iter()
+        // For each action of the (enumerable) action space ...
+        f.action_space()
+            .iter()
+            .ok_or(Error::NonEnumerableSpace)?
             // ... evaluate the value function, then ...
-            .map(|a| (a, f.call(a, state)))
+            .map(|a| {
+                let r = f.call(&a, state);
+                (a, r)
+            })
             // ... for each (action, reward) pair ...
             .reduce(|(a_i, r_i), (a_j, r_j)|
             // ... maximize the expected reward ...
@@ -32,9 +39,8 @@ impl Policy for Greedy {
             })
             // ... and get the associated action ...
             .map(|(a, _)| a)
-            // ... or panic if sequence is empty.
-            .expect("Unable to choose an action")
-            .clone()
+            // ... or fail if sequence is empty.
+            .ok_or(Error::EmptyActionSpace)
     }
 
     fn reset(&mut self) {}
@@ -1,5 +1,5 @@
 mod policy;
-pub use policy::Policy;
+pub use policy::{ParametricPolicy, Policy};
 
 mod greedy;
 pub use greedy::Greedy;
@@ -9,3 +9,6 @@ pub use epsilon_greedy::EpsilonGreedy;
 
 mod random;
 pub use random::Random;
+
+mod gaussian;
+pub use gaussian::Gaussian;
@@ -3,8 +3,10 @@ use std::fmt::Debug;
 use rand::Rng;
 
 use crate::{
-    types::{Action, Reward, State},
+    spaces::Space,
+    types::{Action, Observation, Reward},
     values::StateActionValue,
+    Result,
 };
 
 /// Definition of generic policy.
@@ -14,10 +16,60 @@ pub trait Policy: Clone + Debug + Default {
     where
         A: Action,
         R: Reward,
-        S: State,
+        S: Observation,
+        V: StateActionValue<A, R, S>,
+        T: Rng + ?Sized,
+    {
+        self.try_call(f, state, rng).expect("Unable to choose an action")
+    }
+
+    /// Chooses the next action given a sequence of (action, expected_reward),
+    /// failing if the action space is empty.
+    fn try_call<A, R, S, V, T>(&self, f: &V, state: &S, rng: &mut T) -> Result<A>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
         V: StateActionValue<A, R, S>,
         T: Rng + ?Sized;
 
     /// Resets the function.
     fn reset(&mut self);
+
+    /// Advances the policy by one update of the agent, e.g. decaying its exploration rate.
+    fn step(&mut self) {}
+}
+
+/// Definition of a parametric policy, i.e. a stochastic policy with a differentiable log-probability,
+/// which chooses actions without enumerating the action space, e.g. a continuous one.
+pub trait ParametricPolicy<A, S>: Clone + Debug
+where
+    A: Action,
+    S: Observation,
+{
+    /// Gets the action space.
+    fn action_space(&self) -> &dyn Space<A>;
+
+    /// Gets the state space.
+    fn state_space(&self) -> &dyn Space<S>;
+
+    /// Gets the parameters of the policy.
+    fn parameters(&self) -> &[f64];
+
+    /// Samples the next action for given state.
+    fn call<T>(&self, state: &S, rng: &mut T) -> A
+    where
+        T: Rng + ?Sized;
+
+    /// Computes the most likely action for given state, e.g. in evaluation mode.
+    fn mode(&self, state: &S) -> A;
+
+    /// Computes the gradient of the log-probability of an action in a state with respect to the parameters.
+    fn grad_log_prob(&self, action: &A, state: &S) -> Vec<f64>;
+
+    /// Updates the parameters adding the given step, e.g. a scaled gradient for gradient ascent.
+    fn update(&mut self, step: &[f64]);
+
+    /// Resets the parameters.
+    fn reset(&mut self);
 }
@@ -1,33 +1,38 @@
 use std::fmt::Debug;
 
-use rand::{prelude::IteratorRandom, Rng};
+use rand::Rng;
 
 use super::Policy;
 use crate::{
-    types::{Action, Reward, State},
+    types::{Action, Observation, Reward},
     values::StateActionValue,
+    Error, Result,
 };
 
 /// Random policy.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Random {}
 
 impl Random {}
 
 impl Policy for Random {
-    fn call<A, R, S, V, T>(&self, f: &V, _state: &S, rng: &mut T) -> A
+    fn try_call<A, R, S, V, T>(&self, f: &V, _state: &S, rng: &mut T) -> Result<A>
     where
         A: Action,
         R: Reward,
-        S: State,
+        S: Observation,
         V: StateActionValue<A, R, S>,
         T: Rng + ?Sized,
     {
+        let space = f.action_space();
+        // Check the action space is not empty.
+        if space.is_empty() {
+            return Err(Error::EmptyActionSpace);
+        }
         // Select a random action form the action space.
-        f.actions_iter()
-            .choose(rng)
-            .expect("Unable to choose an action")
-            .clone()
+        let mut rng = rng;
+        Ok(space.sample_dyn(&mut rng))
     }
 
     fn reset(&mut self) {}
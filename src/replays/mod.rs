@@ -0,0 +1,13 @@
+mod replay;
+pub use replay::Replay;
+
+mod sum_tree;
+
+mod uniform;
+pub use uniform::Uniform;
+
+mod prioritized;
+pub use prioritized::Prioritized;
+
+mod n_step;
+pub use n_step::NStep;
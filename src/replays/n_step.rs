@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+use crate::types::{Action, Observation, Reward, Transition};
+
+/// Aggregator of n-step transitions, as in Sutton & Barto (Section 7.1).
+///
+/// Consecutive transitions of an episode are aggregated into a single transition from the first state
+/// and action to the state `n` steps later, whose reward is the discounted sum of the `n` rewards,
+/// hence its value should be bootstrapped with discount `gamma^n`, see [`NStep::discount`]. At the end
+/// of an episode, the remaining transitions are aggregated up to the last state, without bootstrapping.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NStep<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    n: usize,
+    gamma: f64,
+    transitions: VecDeque<Transition<A, R, S>>,
+}
+
+impl<A, R, S> NStep<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    /// Constructs an aggregator given the number of steps and the discount factor of the rewards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of steps is not positive or if the discount factor is not in `[0, 1]`.
+    pub fn new(n: usize, gamma: f64) -> Self {
+        assert!(n > 0, "Number of steps must be positive");
+        assert!((0. ..=1.).contains(&gamma), "Discount factor must be in [0, 1]");
+
+        Self {
+            n,
+            gamma,
+            transitions: VecDeque::new(),
+        }
+    }
+
+    /// Gets the discount of the value of the next state of the aggregated transitions, i.e. `gamma^n`.
+    pub fn discount(&self) -> f64 {
+        self.gamma.powi(self.n as i32)
+    }
+
+    /// Pushes the next transition of the episode, returning the aggregated transitions that are complete,
+    /// i.e. the one starting `n - 1` steps before, if any, or all the remaining ones if the episode is over.
+    pub fn push(&mut self, transition: Transition<A, R, S>) -> Vec<Transition<A, f64, S>> {
+        let is_done = transition.is_done;
+        self.transitions.push_back(transition);
+
+        let mut aggregated = Vec::new();
+        // Aggregate the first transition once it is followed by n - 1 ones, or all of them at the end.
+        while self.transitions.len() == self.n || (is_done && !self.transitions.is_empty()) {
+            aggregated.push(self.aggregate());
+            self.transitions.pop_front();
+        }
+
+        aggregated
+    }
+
+    /// Discards the pending transitions, e.g. if the episode is truncated.
+    pub fn clear(&mut self) {
+        self.transitions.clear();
+    }
+
+    /// Aggregates the pending transitions, from the first to the last one.
+    fn aggregate(&self) -> Transition<A, f64, S> {
+        let (first, last) = (&self.transitions[0], &self.transitions[self.transitions.len() - 1]);
+        let reward = self
+            .transitions
+            .iter()
+            .rev()
+            .fold(0., |g, t| t.reward.as_() + self.gamma * g);
+
+        Transition {
+            state: first.state.clone(),
+            action: first.action.clone(),
+            reward,
+            next_state: last.next_state.clone(),
+            is_done: last.is_done,
+        }
+    }
+}
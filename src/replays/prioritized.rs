@@ -0,0 +1,173 @@
+use rand::Rng;
+
+use super::{sum_tree::SumTree, Replay};
+use crate::types::{Action, Observation, Reward, Transition};
+
+/// Prioritized experience replay, as in Schaul et al. (2016).
+///
+/// Each transition is sampled proportionally to its priority raised to `alpha`, where the priority is
+/// the magnitude of its last error plus a small constant, and new transitions are given the maximum
+/// priority seen so far, so that they are sampled at least once. The bias of the sampling is corrected
+/// by importance-sampling weights raised to `beta`, which are normalized by their maximum in the batch.
+/// The priorities are stored in a sum-tree, hence sampling and updating take logarithmic time.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Prioritized<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    capacity: usize,
+    position: usize,
+    transitions: Vec<Transition<A, R, S>>,
+    priorities: SumTree,
+    max_priority: f64,
+    alpha: f64,
+    beta: f64,
+}
+
+impl<A, R, S> Prioritized<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    /// Offset of the priorities, so that transitions with zero error are still sampled.
+    const EPSILON: f64 = 1e-6;
+
+    /// Constructs an empty replay memory given its capacity, with `alpha = 0.6` and `beta = 0.4`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is not positive.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be positive");
+
+        Self {
+            capacity,
+            position: 0,
+            transitions: Vec::new(),
+            priorities: SumTree::new(capacity),
+            max_priority: 1.,
+            alpha: 0.6,
+            beta: 0.4,
+        }
+    }
+
+    /// Sets the exponent of the priorities, where zero is uniform sampling.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent is negative.
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        assert!(alpha >= 0., "Alpha must be non-negative");
+        self.alpha = alpha;
+
+        self
+    }
+
+    /// Sets the exponent of the importance-sampling weights, see [`Prioritized::set_beta`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent is not in `[0, 1]`.
+    pub fn with_beta(mut self, beta: f64) -> Self {
+        self.set_beta(beta);
+
+        self
+    }
+
+    /// Gets the exponent of the importance-sampling weights.
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Sets the exponent of the importance-sampling weights, where one fully corrects the bias,
+    /// e.g. to anneal it towards one during training.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent is not in `[0, 1]`.
+    pub fn set_beta(&mut self, beta: f64) {
+        assert!((0. ..=1.).contains(&beta), "Beta must be in [0, 1]");
+        self.beta = beta;
+    }
+
+    /// Updates the priority of the transition stored at the given index given its error,
+    /// e.g. the temporal-difference error of its last update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no transition is stored at the given index.
+    pub fn update(&mut self, index: usize, error: f64) {
+        assert!(index < self.transitions.len(), "Unknown transition {}", index);
+
+        let priority = error.abs() + Self::EPSILON;
+        self.max_priority = self.max_priority.max(priority);
+        self.priorities.set(index, priority.powf(self.alpha));
+    }
+}
+
+impl<A, R, S> Replay<A, R, S> for Prioritized<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&Transition<A, R, S>> {
+        self.transitions.get(index)
+    }
+
+    fn push(&mut self, transition: Transition<A, R, S>) {
+        // Append the transition until the memory is full, then overwrite the oldest one ...
+        match self.transitions.len() < self.capacity {
+            false => self.transitions[self.position] = transition,
+            true => self.transitions.push(transition),
+        }
+        // ... with the maximum priority.
+        self.priorities.set(self.position, self.max_priority.powf(self.alpha));
+        self.position = (self.position + 1) % self.capacity;
+    }
+
+    fn sample<T>(&self, batch_size: usize, rng: &mut T) -> Vec<(usize, &Transition<A, R, S>, f64)>
+    where
+        T: Rng + ?Sized,
+    {
+        assert!(!self.is_empty(), "Unable to sample from empty replay memory");
+
+        let (total, n) = (self.priorities.total(), self.transitions.len() as f64);
+        // Split the total priority into equal segments, then sample a transition from each one ...
+        let segment = total / batch_size as f64;
+        let indices: Vec<usize> = (0..batch_size)
+            .map(|i| self.priorities.find(segment * (i as f64 + rng.gen::<f64>())))
+            .collect();
+        // ... and weight it by the inverse of its probability, relative to the maximum weight.
+        let weights: Vec<f64> = indices
+            .iter()
+            .map(|&i| (n * self.priorities.get(i) / total).powf(-self.beta))
+            .collect();
+        let max = weights.iter().cloned().fold(f64::MIN_POSITIVE, f64::max);
+
+        indices
+            .into_iter()
+            .zip(weights)
+            .map(|(i, w)| (i, &self.transitions[i], w / max))
+            .collect()
+    }
+
+    fn clear(&mut self) {
+        self.transitions.clear();
+        self.priorities.clear();
+        self.position = 0;
+        self.max_priority = 1.;
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt::Debug;
+
+use rand::Rng;
+
+use crate::types::{Action, Observation, Reward, Transition};
+
+/// Definition of an experience replay memory, i.e. a fixed-capacity store of transitions to sample from.
+///
+/// Transitions are stored in slots, where the oldest transition is overwritten once the memory is full,
+/// hence the index of a sampled transition identifies it until it is overwritten.
+pub trait Replay<A, R, S>: Clone + Debug
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    /// Gets the maximum number of stored transitions.
+    fn capacity(&self) -> usize;
+
+    /// Gets the number of stored transitions.
+    fn len(&self) -> usize;
+
+    /// Checks whether there are no stored transitions.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the transition stored at the given index, if any.
+    fn get(&self, index: usize) -> Option<&Transition<A, R, S>>;
+
+    /// Stores the given transition, overwriting the oldest one if the memory is full.
+    fn push(&mut self, transition: Transition<A, R, S>);
+
+    /// Samples a batch of stored transitions, as (index, transition, importance-sampling weight) triplets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the memory is empty.
+    fn sample<T>(&self, batch_size: usize, rng: &mut T) -> Vec<(usize, &Transition<A, R, S>, f64)>
+    where
+        T: Rng + ?Sized;
+
+    /// Removes all the stored transitions.
+    fn clear(&mut self);
+}
@@ -0,0 +1,66 @@
+/// Sum-tree over non-negative priorities, i.e. a binary tree whose nodes are the sums of their children,
+/// which updates a priority and samples proportionally to the priorities in logarithmic time.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct SumTree {
+    size: usize,
+    nodes: Vec<f64>,
+}
+
+impl SumTree {
+    /// Constructs a sum-tree given the number of priorities, all set to zero.
+    pub(crate) fn new(capacity: usize) -> Self {
+        // Pad the leaves to a power of two, so that the tree is complete.
+        let size = capacity.next_power_of_two();
+
+        Self {
+            size,
+            nodes: vec![0.; 2 * size],
+        }
+    }
+
+    /// Gets the sum of the priorities.
+    pub(crate) fn total(&self) -> f64 {
+        self.nodes[1]
+    }
+
+    /// Gets the priority of the given index.
+    pub(crate) fn get(&self, index: usize) -> f64 {
+        self.nodes[self.size + index]
+    }
+
+    /// Sets the priority of the given index, updating the sums of its ancestors.
+    pub(crate) fn set(&mut self, index: usize, priority: f64) {
+        let mut node = self.size + index;
+        self.nodes[node] = priority;
+        while node > 1 {
+            node /= 2;
+            self.nodes[node] = self.nodes[2 * node] + self.nodes[2 * node + 1];
+        }
+    }
+
+    /// Finds the index whose cumulative priority interval contains the given value in `[0, total)`.
+    ///
+    /// Only subtrees with positive priority are descended, hence the found index has positive priority
+    /// even if the value exceeds the total due to rounding errors.
+    pub(crate) fn find(&self, mut value: f64) -> usize {
+        let mut node = 1;
+        while node < self.size {
+            let left = 2 * node;
+            match value < self.nodes[left] || self.nodes[left + 1] <= 0. {
+                true => node = left,
+                false => {
+                    value -= self.nodes[left];
+                    node = left + 1;
+                }
+            }
+        }
+
+        node - self.size
+    }
+
+    /// Sets all the priorities to zero.
+    pub(crate) fn clear(&mut self) {
+        self.nodes.iter_mut().for_each(|x| *x = 0.);
+    }
+}
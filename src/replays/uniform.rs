@@ -0,0 +1,88 @@
+use rand::Rng;
+
+use super::Replay;
+use crate::types::{Action, Observation, Reward, Transition};
+
+/// Uniform experience replay, i.e. a ring buffer of transitions sampled uniformly with replacement,
+/// as in Lin (1992) and Mnih et al. (2015).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uniform<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    capacity: usize,
+    position: usize,
+    transitions: Vec<Transition<A, R, S>>,
+}
+
+impl<A, R, S> Uniform<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    /// Constructs an empty replay memory given its capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is not positive.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be positive");
+
+        Self {
+            capacity,
+            position: 0,
+            transitions: Vec::new(),
+        }
+    }
+}
+
+impl<A, R, S> Replay<A, R, S> for Uniform<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&Transition<A, R, S>> {
+        self.transitions.get(index)
+    }
+
+    fn push(&mut self, transition: Transition<A, R, S>) {
+        // Append the transition until the memory is full, then overwrite the oldest one.
+        match self.transitions.len() < self.capacity {
+            false => self.transitions[self.position] = transition,
+            true => self.transitions.push(transition),
+        }
+        self.position = (self.position + 1) % self.capacity;
+    }
+
+    fn sample<T>(&self, batch_size: usize, rng: &mut T) -> Vec<(usize, &Transition<A, R, S>, f64)>
+    where
+        T: Rng + ?Sized,
+    {
+        assert!(!self.is_empty(), "Unable to sample from empty replay memory");
+
+        (0..batch_size)
+            .map(|_| {
+                let i = rng.gen_range(0..self.transitions.len());
+                (i, &self.transitions[i], 1.)
+            })
+            .collect()
+    }
+
+    fn clear(&mut self) {
+        self.transitions.clear();
+        self.position = 0;
+    }
+}
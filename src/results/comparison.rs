@@ -0,0 +1,189 @@
+use polars::prelude::*;
+use rand::Rng;
+
+use super::{bonferroni, holm, mann_whitney, mean, paired_bootstrap, welch, Significance};
+use crate::Result;
+
+/// Two-sample significance test.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Test {
+    /// Welch's t-test, with Cohen's d effect size.
+    #[default]
+    Welch,
+    /// Mann-Whitney U test, with rank-biserial correlation effect size.
+    MannWhitney,
+    /// Paired bootstrap test with given number of resamples, with Cohen's d of the differences effect size.
+    PairedBootstrap(usize),
+}
+
+/// Correction of the p-values for multiple comparisons.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Correction {
+    /// No correction.
+    None,
+    /// Bonferroni correction.
+    Bonferroni,
+    /// Holm step-down correction.
+    #[default]
+    Holm,
+}
+
+/// Pairwise significance testing between the groups of session results, e.g. the agents of a benchmark.
+///
+/// The rows of the given data frame are grouped by the group column, e.g. `agent`, then the values of the
+/// value column, e.g. `reward`, are averaged for each unit of the unit column, e.g. `reps`, so that each
+/// repetition is an observation. Paired tests match observations by unit, hence units should be shared by
+/// the groups, e.g. with the common random numbers of a benchmark. The resulting data frame contains one
+/// row for each pair of groups, ordered by first occurrence, with the `a` and `b` group names, their
+/// `mean_a` and `mean_b` means, the test `statistic`, the `effect` size, the `p_value`, the `p_adjusted`
+/// p-value corrected for multiple comparisons and whether the difference is `significant`.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    group: String,
+    value: String,
+    unit: Option<String>,
+    test: Test,
+    correction: Correction,
+    alpha: f64,
+}
+
+impl Comparison {
+    /// Constructs a comparison given the group column and the value column.
+    pub fn new<S, T>(group: S, value: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            group: group.into(),
+            value: value.into(),
+            unit: Some("reps".into()),
+            test: Default::default(),
+            correction: Default::default(),
+            alpha: 0.05,
+        }
+    }
+
+    /// Sets the unit column of the observations, where none uses each row as an observation.
+    pub fn with_unit<S>(mut self, unit: Option<S>) -> Self
+    where
+        S: Into<String>,
+    {
+        self.unit = unit.map(Into::into);
+
+        self
+    }
+
+    /// Sets the significance test.
+    pub fn with_test(mut self, test: Test) -> Self {
+        self.test = test;
+
+        self
+    }
+
+    /// Sets the correction for multiple comparisons.
+    pub fn with_correction(mut self, correction: Correction) -> Self {
+        self.correction = correction;
+
+        self
+    }
+
+    /// Sets the significance level.
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        assert!(0. < alpha && alpha < 1., "Significance level must be in (0, 1)");
+        self.alpha = alpha;
+
+        self
+    }
+
+    /// Collects the observations of each group, in order of first occurrence.
+    fn observations(&self, data: &DataFrame) -> Result<Vec<(String, Vec<f64>)>> {
+        // Group the rows by group and unit.
+        let mut by = vec![self.group.as_str()];
+        by.extend(self.unit.as_deref());
+        let groups = data.groupby_stable(&by)?;
+        let groups = groups.get_groups().clone().into_idx();
+        // Cast names to strings and values to floating point.
+        let names = data.column(&self.group)?.cast(&DataType::Utf8)?;
+        let names = names.utf8()?;
+        let values = data.column(&self.value)?.cast(&DataType::Float64)?;
+        let values: Vec<_> = values.f64()?.into_iter().collect();
+        // Compute the mean value of each unit, then collect them by group.
+        let mut observations: Vec<(String, Vec<f64>)> = vec![];
+        for (first, group) in groups.first().iter().zip(groups.all()) {
+            let name = names.get(*first as usize).unwrap_or_default();
+            let x: Vec<_> = group.iter().filter_map(|&i| values[i as usize]).collect();
+            let x = match self.unit {
+                Some(_) => vec![mean(&x)],
+                None => x,
+            };
+            match observations.iter_mut().find(|(n, _)| n == name) {
+                Some((_, o)) => o.extend(x),
+                None => observations.push((name.to_string(), x)),
+            }
+        }
+
+        Ok(observations)
+    }
+
+    /// Performs the pairwise significance tests on the given data frame.
+    pub fn call<T>(&self, data: &DataFrame, rng: &mut T) -> DataFrame
+    where
+        T: Rng + ?Sized,
+    {
+        self.try_call(data, rng).expect("Unable to perform comparisons")
+    }
+
+    /// Performs the pairwise significance tests on the given data frame,
+    /// failing if the group or value columns are missing or the values are not numeric.
+    pub fn try_call<T>(&self, data: &DataFrame, rng: &mut T) -> Result<DataFrame>
+    where
+        T: Rng + ?Sized,
+    {
+        let observations = self.observations(data)?;
+        // For each pair of groups ...
+        let (mut a, mut b, mut mean_a, mut mean_b) = (vec![], vec![], vec![], vec![]);
+        let (mut statistic, mut effect, mut p_value) = (vec![], vec![], vec![]);
+        for (i, (name_a, x)) in observations.iter().enumerate() {
+            for (name_b, y) in &observations[i + 1..] {
+                // ... perform the significance test.
+                let Significance {
+                    statistic: s,
+                    p_value: p,
+                    effect: e,
+                } = match self.test {
+                    Test::Welch => welch(x, y),
+                    Test::MannWhitney => mann_whitney(x, y),
+                    Test::PairedBootstrap(resamples) => paired_bootstrap(x, y, resamples, rng),
+                };
+                a.push(name_a.as_str());
+                b.push(name_b.as_str());
+                mean_a.push(mean(x));
+                mean_b.push(mean(y));
+                statistic.push(s);
+                effect.push(e);
+                p_value.push(p);
+            }
+        }
+        // Correct the p-values for multiple comparisons.
+        let p_adjusted = match self.correction {
+            Correction::None => p_value.clone(),
+            Correction::Bonferroni => bonferroni(&p_value),
+            Correction::Holm => holm(&p_value),
+        };
+        let significant: Vec<_> = p_adjusted.iter().map(|&p| p < self.alpha).collect();
+
+        // Cast data to polars DataFrame.
+        Ok(DataFrame::new(vec![
+            Series::new("a", a),
+            Series::new("b", b),
+            ChunkedArray::<Float64Type>::from_vec("mean_a", mean_a).into_series(),
+            ChunkedArray::<Float64Type>::from_vec("mean_b", mean_b).into_series(),
+            ChunkedArray::<Float64Type>::from_vec("statistic", statistic).into_series(),
+            ChunkedArray::<Float64Type>::from_vec("effect", effect).into_series(),
+            ChunkedArray::<Float64Type>::from_vec("p_value", p_value).into_series(),
+            ChunkedArray::<Float64Type>::from_vec("p_adjusted", p_adjusted).into_series(),
+            Series::new("significant", significant),
+        ])?)
+    }
+}
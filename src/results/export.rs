@@ -0,0 +1,176 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use polars::prelude::*;
+
+use crate::Result;
+
+/// File format of exported session results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Comma-separated values.
+    #[default]
+    Csv,
+    /// JSON Lines, i.e. one JSON object for each row.
+    #[cfg(feature = "json")]
+    JsonLines,
+    /// Arrow IPC, i.e. the Feather (v2) file format.
+    #[cfg(feature = "ipc")]
+    Ipc,
+    /// Apache Parquet, i.e. a compressed columnar file format.
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+/// Metadata of an experiment run, which makes exported results self-describing.
+///
+/// The metadata records the crate version and the creation timestamp, together with the optional seed,
+/// the agent and environment descriptions, e.g. their `Debug` representation including their parameters,
+/// and any additional entry. Timestamps are seconds since the UNIX epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    entries: BTreeMap<String, String>,
+}
+
+impl Metadata {
+    /// Constructs the metadata of a run, recording the crate version and the creation timestamp.
+    pub fn new() -> Self {
+        let mut entries = BTreeMap::new();
+        entries.insert("version".into(), env!("CARGO_PKG_VERSION").into());
+        entries.insert("created".into(), timestamp().to_string());
+
+        Self { entries }
+    }
+
+    /// Sets the seed of the run.
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.with_entry("seed", seed)
+    }
+
+    /// Sets the description of the agent, including its parameters.
+    pub fn with_agent<G>(self, agent: &G) -> Self
+    where
+        G: Debug,
+    {
+        self.with_entry("agent", format!("{:?}", agent))
+    }
+
+    /// Sets the description of the environment, including its parameters.
+    pub fn with_environment<E>(self, environment: &E) -> Self
+    where
+        E: Debug,
+    {
+        self.with_entry("environment", format!("{:?}", environment))
+    }
+
+    /// Sets an additional entry.
+    pub fn with_entry<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: ToString,
+    {
+        self.entries.insert(key.into(), value.to_string());
+
+        self
+    }
+
+    /// Gets the entries of the metadata.
+    pub fn entries(&self) -> &BTreeMap<String, String> {
+        &self.entries
+    }
+
+    /// Serializes the metadata as a JSON object.
+    pub fn to_json(&self) -> String {
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(k, v)| format!("  {}: {}", escape(k), escape(v)))
+            .collect();
+
+        format!("{{\n{}\n}}\n", entries.join(",\n"))
+    }
+}
+
+impl Default for Metadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gets the current timestamp, in seconds since the UNIX epoch.
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|t| t.as_secs())
+        .unwrap_or_default()
+}
+
+/// Escapes a string as a JSON string literal.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+/// Gets the path of the metadata sidecar of the given results path, i.e. with `meta.json` extension.
+pub fn metadata_path<P>(path: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    path.as_ref().with_extension("meta.json")
+}
+
+/// Exports session results to the given path with the given format,
+/// storing the metadata alongside in a JSON sidecar file, see [`metadata_path`].
+///
+/// The metadata is completed with the export timestamp and the results shape.
+pub fn export<P>(data: &mut DataFrame, path: P, format: Format, metadata: &Metadata) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    // Write the results.
+    let file = BufWriter::new(File::create(path)?);
+    match format {
+        Format::Csv => CsvWriter::new(file).finish(data)?,
+        #[cfg(feature = "json")]
+        Format::JsonLines => JsonWriter::new(file)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(data)?,
+        #[cfg(feature = "ipc")]
+        Format::Ipc => IpcWriter::new(file).finish(data)?,
+        #[cfg(feature = "parquet")]
+        Format::Parquet => ParquetWriter::new(file).finish(data)?,
+    }
+    // Write the metadata sidecar.
+    let metadata = metadata
+        .clone()
+        .with_entry("exported", timestamp())
+        .with_entry("format", format!("{:?}", format))
+        .with_entry("rows", data.height())
+        .with_entry("columns", data.get_column_names().join(","));
+    let mut file = BufWriter::new(File::create(metadata_path(path))?);
+    file.write_all(metadata.to_json().as_bytes())?;
+    file.flush()?;
+
+    Ok(())
+}
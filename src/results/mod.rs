@@ -0,0 +1,12 @@
+mod statistics;
+pub use statistics::{bonferroni, cohen_d, holm, mann_whitney, paired_bootstrap, welch, Significance};
+pub(crate) use statistics::{bootstrap, mean, quantile, t_quantile, variance};
+
+mod summary;
+pub use summary::{Interval, Summary};
+
+mod comparison;
+pub use comparison::{Comparison, Correction, Test};
+
+mod export;
+pub use export::{export, metadata_path, Format, Metadata};
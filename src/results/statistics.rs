@@ -0,0 +1,185 @@
+use rand::Rng;
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+/// Computes the sample mean.
+pub fn mean(x: &[f64]) -> f64 {
+    x.iter().sum::<f64>() / x.len() as f64
+}
+
+/// Computes the unbiased sample variance.
+pub fn variance(x: &[f64]) -> f64 {
+    let m = mean(x);
+
+    x.iter().map(|x| (x - m).powi(2)).sum::<f64>() / (x.len() as f64 - 1.)
+}
+
+/// Computes the quantile of sorted values, interpolating linearly between order statistics.
+pub fn quantile(sorted: &[f64], q: f64) -> f64 {
+    match sorted.len() {
+        0 => f64::NAN,
+        n => {
+            let h = q * (n - 1) as f64;
+            let (i, j) = (h.floor() as usize, h.ceil() as usize);
+
+            sorted[i] + (h - i as f64) * (sorted[j] - sorted[i])
+        }
+    }
+}
+
+/// Computes the quantile of the Student's t distribution with given degrees of freedom.
+pub fn t_quantile(p: f64, dof: f64) -> f64 {
+    match StudentsT::new(0., 1., dof) {
+        Ok(t) => t.inverse_cdf(p),
+        Err(_) => f64::NAN,
+    }
+}
+
+/// Computes the percentile bootstrap distribution of a statistic, sorted in ascending order.
+pub fn bootstrap<F, T>(x: &[f64], resamples: usize, statistic: F, rng: &mut T) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+    T: Rng + ?Sized,
+{
+    // Check for empty values.
+    if x.is_empty() {
+        return vec![];
+    }
+    let mut sample = vec![0.; x.len()];
+    let mut statistics: Vec<_> = (0..resamples)
+        .map(|_| {
+            // Resample with replacement.
+            sample.iter_mut().for_each(|s| *s = x[rng.gen_range(0..x.len())]);
+            statistic(&sample)
+        })
+        .collect();
+    statistics.sort_by(f64::total_cmp);
+
+    statistics
+}
+
+/// Outcome of a two-sample significance test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Significance {
+    /// Test statistic.
+    pub statistic: f64,
+    /// Two-sided p-value.
+    pub p_value: f64,
+    /// Effect size.
+    pub effect: f64,
+}
+
+/// Computes the Cohen's d effect size of two samples, with pooled standard deviation.
+pub fn cohen_d(x: &[f64], y: &[f64]) -> f64 {
+    let (n, m) = (x.len() as f64, y.len() as f64);
+    let s = (((n - 1.) * variance(x) + (m - 1.) * variance(y)) / (n + m - 2.)).sqrt();
+
+    (mean(x) - mean(y)) / s
+}
+
+/// Performs the Welch's t-test of two samples with unequal variances.
+///
+/// The statistic is the t statistic and the effect size is the Cohen's d.
+pub fn welch(x: &[f64], y: &[f64]) -> Significance {
+    let (n, m) = (x.len() as f64, y.len() as f64);
+    let (a, b) = (variance(x) / n, variance(y) / m);
+    let statistic = (mean(x) - mean(y)) / (a + b).sqrt();
+    // Compute the Welch-Satterthwaite degrees of freedom.
+    let dof = (a + b).powi(2) / (a.powi(2) / (n - 1.) + b.powi(2) / (m - 1.));
+    let p_value = match StudentsT::new(0., 1., dof) {
+        Ok(t) => 2. * (1. - t.cdf(statistic.abs())),
+        Err(_) => f64::NAN,
+    };
+
+    Significance {
+        statistic,
+        p_value,
+        effect: cohen_d(x, y),
+    }
+}
+
+/// Performs the Mann-Whitney U test of two samples, with normal approximation and tie correction.
+///
+/// The statistic is the U statistic of the first sample and the effect size is the rank-biserial correlation,
+/// which is positive if the first sample tends to be greater than the second.
+pub fn mann_whitney(x: &[f64], y: &[f64]) -> Significance {
+    let (n, m) = (x.len() as f64, y.len() as f64);
+    // Sort the pooled samples, remembering the origin of each value.
+    let mut pooled: Vec<_> = x
+        .iter()
+        .map(|&v| (v, true))
+        .chain(y.iter().map(|&v| (v, false)))
+        .collect();
+    pooled.sort_by(|a, b| a.0.total_cmp(&b.0));
+    // Assign average ranks to ties, while computing the tie correction.
+    let (mut rank_sum, mut ties, mut i) = (0., 0., 0);
+    while i < pooled.len() {
+        let j = i + pooled[i..].iter().take_while(|v| v.0 == pooled[i].0).count();
+        let rank = (i + j + 1) as f64 / 2.;
+        rank_sum += rank * pooled[i..j].iter().filter(|v| v.1).count() as f64;
+        ties += ((j - i).pow(3) - (j - i)) as f64;
+        i = j;
+    }
+    let statistic = rank_sum - n * (n + 1.) / 2.;
+    // Approximate the distribution of the statistic with a normal distribution.
+    let mu = n * m / 2.;
+    let sigma = (n * m / 12. * ((n + m + 1.) - ties / ((n + m) * (n + m - 1.)))).sqrt();
+    let z = ((statistic - mu).abs() - 0.5).max(0.) / sigma;
+    let p_value = match Normal::new(0., 1.) {
+        Ok(normal) => (2. * (1. - normal.cdf(z))).min(1.),
+        Err(_) => f64::NAN,
+    };
+
+    Significance {
+        statistic,
+        p_value,
+        effect: 2. * statistic / (n * m) - 1.,
+    }
+}
+
+/// Performs the paired bootstrap test of two samples, resampling the centered paired differences.
+///
+/// The statistic is the mean difference and the effect size is the Cohen's d of the differences.
+pub fn paired_bootstrap<T>(x: &[f64], y: &[f64], resamples: usize, rng: &mut T) -> Significance
+where
+    T: Rng + ?Sized,
+{
+    assert_eq!(x.len(), y.len(), "Paired samples must have the same length");
+    let d: Vec<_> = x.iter().zip(y).map(|(x, y)| x - y).collect();
+    let statistic = mean(&d);
+    // Center the differences to satisfy the null hypothesis.
+    let centered: Vec<_> = d.iter().map(|d| d - statistic).collect();
+    let means = bootstrap(&centered, resamples, mean, rng);
+    let extreme = means.iter().filter(|m| m.abs() >= statistic.abs()).count();
+    // Count the observed statistic as well, so that the p-value is never zero.
+    let p_value = (extreme + 1) as f64 / (resamples + 1) as f64;
+
+    Significance {
+        statistic,
+        p_value,
+        effect: statistic / variance(&d).sqrt(),
+    }
+}
+
+/// Adjusts p-values for multiple comparisons with the Bonferroni correction.
+pub fn bonferroni(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len() as f64;
+
+    p_values.iter().map(|p| (p * m).min(1.)).collect()
+}
+
+/// Adjusts p-values for multiple comparisons with the Holm step-down correction.
+pub fn holm(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len();
+    // Sort the p-values in ascending order.
+    let mut order: Vec<_> = (0..m).collect();
+    order.sort_by(|&i, &j| p_values[i].total_cmp(&p_values[j]));
+    // Compute the cumulative maximum of the scaled p-values.
+    let mut adjusted = vec![0.; m];
+    let mut max = 0_f64;
+    for (k, &i) in order.iter().enumerate() {
+        max = max.max(((m - k) as f64 * p_values[i]).min(1.));
+        adjusted[i] = max;
+    }
+
+    adjusted
+}
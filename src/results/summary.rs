@@ -0,0 +1,162 @@
+use polars::prelude::*;
+use rand::Rng;
+
+use super::{bootstrap, mean, quantile, t_quantile, variance};
+use crate::Result;
+
+/// Confidence interval of the mean.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interval {
+    /// Student's t interval with given confidence level.
+    T(f64),
+    /// Percentile bootstrap interval with given confidence level and number of resamples.
+    Bootstrap(f64, usize),
+}
+
+impl Default for Interval {
+    fn default() -> Self {
+        Self::T(0.95)
+    }
+}
+
+/// Summary statistics of session results, aggregated across repetitions.
+///
+/// The rows of the given data frame are grouped by the key columns, e.g. `episode` or `step`, then,
+/// for each value column, e.g. `reward`, the count of non-null values, the mean, the standard deviation,
+/// the standard error of the mean, the median, the quantiles and the confidence interval of the mean
+/// are computed. The resulting data frame contains one row for each group, ordered by first occurrence,
+/// with the key columns followed by the `<value>_<statistic>` columns, where quantiles are named after
+/// their percent rounded to two decimals, e.g. `reward_q25`, and the interval bounds are named
+/// `<value>_lower` and `<value>_upper`.
+#[derive(Clone, Debug)]
+pub struct Summary {
+    by: Vec<String>,
+    values: Vec<String>,
+    quantiles: Vec<f64>,
+    interval: Interval,
+}
+
+impl Summary {
+    /// Constructs a summary given the key columns and the value columns.
+    pub fn new<I, J, S, T>(by: I, values: J) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        J: IntoIterator<Item = T>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            by: by.into_iter().map(Into::into).collect(),
+            values: values.into_iter().map(Into::into).collect(),
+            quantiles: vec![0.25, 0.75],
+            interval: Default::default(),
+        }
+    }
+
+    /// Sets the quantiles to compute, in addition to the median.
+    pub fn with_quantiles<I>(mut self, quantiles: I) -> Self
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        self.quantiles = quantiles.into_iter().collect();
+        assert!(
+            self.quantiles.iter().all(|q| (0. ..=1.).contains(q)),
+            "Quantiles must be in [0, 1]"
+        );
+
+        self
+    }
+
+    /// Sets the confidence interval of the mean.
+    pub fn with_interval(mut self, interval: Interval) -> Self {
+        let (Interval::T(level) | Interval::Bootstrap(level, _)) = interval;
+        assert!(0. < level && level < 1., "Confidence level must be in (0, 1)");
+        self.interval = interval;
+
+        self
+    }
+
+    /// Computes the summary statistics of the given data frame.
+    pub fn call<T>(&self, data: &DataFrame, rng: &mut T) -> DataFrame
+    where
+        T: Rng + ?Sized,
+    {
+        self.try_call(data, rng).expect("Unable to compute summary statistics")
+    }
+
+    /// Computes the summary statistics of the given data frame,
+    /// failing if the key or value columns are missing or the values are not numeric.
+    pub fn try_call<T>(&self, data: &DataFrame, rng: &mut T) -> Result<DataFrame>
+    where
+        T: Rng + ?Sized,
+    {
+        // Group the rows by the key columns, if any.
+        let (keys, groups) = match self.by.is_empty() {
+            false => {
+                let groups = data.groupby_stable(&self.by)?;
+                let groups = groups.get_groups().clone().into_idx();
+                let keys = data
+                    .select(&self.by)?
+                    .take_iter(groups.first().iter().map(|&i| i as usize))?;
+
+                (keys.get_columns().to_vec(), groups.all().to_vec())
+            }
+            true => (vec![], vec![(0..data.height() as IdxSize).collect()]),
+        };
+        // Compute the statistics of each value column.
+        let mut columns = keys;
+        for value in &self.values {
+            // Cast values to floating point.
+            let values = data.column(value)?.cast(&DataType::Float64)?;
+            let values: Vec<_> = values.f64()?.into_iter().collect();
+            // Collect the statistics of each group.
+            let mut statistics = vec![vec![]; 7 + self.quantiles.len()];
+            for group in &groups {
+                // Get the non-null values of the group, sorted in ascending order.
+                let mut x: Vec<_> = group.iter().filter_map(|&i| values[i as usize]).collect();
+                x.sort_by(f64::total_cmp);
+                let (n, m, s) = (x.len() as f64, mean(&x), variance(&x).sqrt());
+                let (lower, upper) = self.interval(&x, rng);
+                let row = [n, m, s, s / n.sqrt(), quantile(&x, 0.5)]
+                    .into_iter()
+                    .chain(self.quantiles.iter().map(|&q| quantile(&x, q)))
+                    .chain([lower, upper]);
+                statistics.iter_mut().zip(row).for_each(|(s, x)| s.push(x));
+            }
+            // Cast statistics to polars Series.
+            let names = ["count", "mean", "std", "sem", "median"]
+                .into_iter()
+                .map(String::from)
+                .chain(self.quantiles.iter().map(|q| format!("q{}", (q * 1e4).round() / 1e2)))
+                .chain(["lower", "upper"].map(String::from));
+            for (name, statistic) in names.zip(statistics) {
+                let name = format!("{}_{}", value, name);
+                columns.push(ChunkedArray::<Float64Type>::from_vec(&name, statistic).into_series());
+            }
+        }
+
+        Ok(DataFrame::new(columns)?)
+    }
+
+    /// Computes the confidence interval of the mean of sorted values.
+    fn interval<T>(&self, x: &[f64], rng: &mut T) -> (f64, f64)
+    where
+        T: Rng + ?Sized,
+    {
+        match self.interval {
+            Interval::T(level) => {
+                let n = x.len() as f64;
+                let h = t_quantile((1. + level) / 2., n - 1.) * (variance(x) / n).sqrt();
+                let m = mean(x);
+
+                (m - h, m + h)
+            }
+            Interval::Bootstrap(level, resamples) => {
+                let means = bootstrap(x, resamples, mean, rng);
+                let alpha = (1. - level) / 2.;
+
+                (quantile(&means, alpha), quantile(&means, 1. - alpha))
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use polars::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+use super::Session;
+use crate::{
+    agents::Agent,
+    envs::Env,
+    types::{Action, Observation, Reward},
+    Result,
+};
+
+/// Named factory of agents or environments.
+type Factory<X> = (String, Box<dyn Fn() -> X + Send + Sync>);
+
+/// A benchmark, where each named agent is run through a session on each named environment.
+///
+/// Agents and environments are built by their factories for each combination. Every combination is
+/// given a random number generator with the same seed, which is drawn from the given random number
+/// generator, so that agents are compared on common random numbers. The resulting data frame contains
+/// the rows of the session results of each combination, in (environment, agent) order, prefixed by
+/// the `env` and `agent` name columns.
+pub struct Benchmark<G, E> {
+    agents: Vec<Factory<G>>,
+    environments: Vec<Factory<E>>,
+}
+
+impl<G, E> Benchmark<G, E> {
+    /// Constructs an empty benchmark.
+    pub fn new() -> Self {
+        Self {
+            agents: vec![],
+            environments: vec![],
+        }
+    }
+
+    /// Adds a named agent factory.
+    pub fn with_agent<N, F>(mut self, name: N, factory: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn() -> G + Send + Sync + 'static,
+    {
+        self.agents.push((name.into(), Box::new(factory)));
+
+        self
+    }
+
+    /// Adds a named environment factory.
+    pub fn with_environment<N, F>(mut self, name: N, factory: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn() -> E + Send + Sync + 'static,
+    {
+        self.environments.push((name.into(), Box::new(factory)));
+
+        self
+    }
+
+    /// Executes the session for each combination of environment and agent.
+    pub fn call<A, R, S, P, V, X, T>(&self, session: &X, rng: &mut T) -> DataFrame
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        X: Session,
+        T: Rng + ?Sized,
+    {
+        self.try_call(session, rng).expect("Unable to execute benchmark")
+    }
+
+    /// Executes the session for each combination of environment and agent,
+    /// failing if any session fails or the results of the combinations have different columns.
+    pub fn try_call<A, R, S, P, V, X, T>(&self, session: &X, rng: &mut T) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        X: Session,
+        T: Rng + ?Sized,
+    {
+        // Draw the seed shared by all the combinations.
+        let seed = rng.gen();
+        // For each environment ...
+        let mut data: Option<DataFrame> = None;
+        for (env_name, environment) in &self.environments {
+            // ... and for each agent ...
+            for (agent_name, agent) in &self.agents {
+                // ... execute the session with the shared seed ...
+                let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+                let mut results = session.try_call(&mut agent(), &mut environment(), &mut rng)?;
+                // ... prefix the names of the combination ...
+                let height = results.height();
+                results
+                    .insert_at_idx(0, Series::new("agent", vec![agent_name.as_str(); height]))?
+                    .insert_at_idx(0, Series::new("env", vec![env_name.as_str(); height]))?;
+                // ... and stack the results.
+                data = Some(match data {
+                    None => results,
+                    Some(data) => data.vstack(&results)?,
+                });
+            }
+        }
+
+        Ok(data.unwrap_or_default())
+    }
+}
+
+impl<G, E> Default for Benchmark<G, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,45 @@
+use std::ops::ControlFlow;
+
+use crate::types::{Action, Observation, Reward, Transition};
+
+/// Definition of an experiment session callback, whose hooks are invoked by the session.
+///
+/// Hooks receive the repetition index, the episode index, the agent and the environment,
+/// where episodes are counted from the beginning of each phase of the repetition, e.g. training
+/// and testing, and the agent is in evaluation mode during test episodes. Since repetitions may
+/// be executed in parallel, hooks take a shared reference to the callback, hence callbacks
+/// with state should rely on interior mutability. Every hook does nothing by default.
+#[allow(unused_variables)]
+pub trait Callback<A, R, S, G, E>: Send + Sync
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    /// Invoked when the session starts.
+    fn on_session_start(&self) {}
+
+    /// Invoked when the session ends.
+    fn on_session_end(&self) {}
+
+    /// Invoked when a repetition starts.
+    fn on_repeat_start(&self, repeat: usize, agent: &G, environment: &E) {}
+
+    /// Invoked when a repetition ends.
+    fn on_repeat_end(&self, repeat: usize, agent: &G, environment: &E) {}
+
+    /// Invoked when an episode starts, after the environment is reset.
+    fn on_episode_start(&self, repeat: usize, episode: usize, agent: &G, environment: &E) {}
+
+    /// Invoked when an episode ends, where breaking stops the remaining episodes of the current phase.
+    fn on_episode_end(&self, repeat: usize, episode: usize, agent: &G, environment: &E) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Invoked when a step starts, given the current state.
+    fn on_step_start(&self, repeat: usize, episode: usize, agent: &G, environment: &E, state: &S) {}
+
+    /// Invoked when a step ends, after the agent is updated, given the transition.
+    fn on_step_end(&self, repeat: usize, episode: usize, agent: &G, environment: &E, transition: &Transition<A, R, S>) {
+    }
+}
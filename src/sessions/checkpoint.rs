@@ -0,0 +1,117 @@
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+use crate::Result;
+
+/// Snapshot of a repetition of an experiment session, taken at the end of an episode.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Snapshot<G, E, O> {
+    /// Number of episodes performed.
+    pub episode: usize,
+    /// Whether the repetition is completed.
+    pub done: bool,
+    /// State of the agent.
+    pub agent: G,
+    /// State of the environment.
+    pub environment: E,
+    /// State of the random number generator of the repetition.
+    pub rng: Xoshiro256PlusPlus,
+    /// Data collected so far.
+    pub output: O,
+}
+
+/// Storage of the snapshots of the repetitions of an experiment session.
+pub(crate) trait Checkpointer<G, E, O>: Sync {
+    /// Gets the number of episodes between two snapshots.
+    fn every(&self) -> usize;
+
+    /// Loads the last snapshot of the given repetition, if any.
+    fn load(&self, repeat: usize) -> Result<Option<Snapshot<G, E, O>>>;
+
+    /// Saves the snapshot of the given repetition.
+    fn save(&self, repeat: usize, snapshot: Snapshot<&G, &E, &O>) -> Result<()>;
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use std::{
+        fs::{create_dir_all, read_to_string, rename, write},
+        path::PathBuf,
+    };
+
+    use serde::{de::DeserializeOwned, Serialize};
+
+    use super::{Checkpointer, Snapshot};
+    use crate::Result;
+
+    /// Checkpointing of an experiment session, where a snapshot of each repetition is saved
+    /// every `n` episodes to a JSON file in the given directory, i.e. `<repeat>.json`.
+    ///
+    /// A snapshot contains the agent, the environment, the random number generator of the repetition
+    /// and the data collected so far. If a snapshot of a repetition is found, the repetition resumes
+    /// from it, while completed repetitions are not executed again. Repetitions without a snapshot
+    /// start from the beginning, hence the session should be resumed with the same seed to reproduce
+    /// an uninterrupted run, see [`Session::call_with_checkpoint`](super::super::Session::call_with_checkpoint).
+    #[derive(Clone, Debug)]
+    pub struct Checkpoint {
+        path: PathBuf,
+        every: usize,
+    }
+
+    impl Checkpoint {
+        /// Constructs a checkpoint given the directory and the number of episodes between two snapshots.
+        pub fn new<P>(path: P, every: usize) -> Self
+        where
+            P: Into<PathBuf>,
+        {
+            assert!(every > 0, "Number of episodes between snapshots must be positive");
+
+            Self {
+                path: path.into(),
+                every,
+            }
+        }
+
+        /// Gets the path of the snapshot of the given repetition.
+        pub fn snapshot_path(&self, repeat: usize) -> PathBuf {
+            self.path.join(format!("{}.json", repeat))
+        }
+    }
+
+    impl<G, E, O> Checkpointer<G, E, O> for Checkpoint
+    where
+        G: Serialize + DeserializeOwned,
+        E: Serialize + DeserializeOwned,
+        O: Serialize + DeserializeOwned,
+    {
+        fn every(&self) -> usize {
+            self.every
+        }
+
+        fn load(&self, repeat: usize) -> Result<Option<Snapshot<G, E, O>>> {
+            let path = self.snapshot_path(repeat);
+            // Resume from the beginning if no snapshot has been saved.
+            if !path.exists() {
+                return Ok(None);
+            }
+            let snapshot = read_to_string(path)?;
+
+            Ok(Some(serde_json::from_str(&snapshot)?))
+        }
+
+        fn save(&self, repeat: usize, snapshot: Snapshot<&G, &E, &O>) -> Result<()> {
+            let path = self.snapshot_path(repeat);
+            let snapshot = serde_json::to_string(&snapshot)?;
+            // Write to a temporary file first, so that an interruption does not corrupt the last snapshot.
+            let temp = path.with_extension("json.tmp");
+            create_dir_all(&self.path)?;
+            write(&temp, snapshot)?;
+            rename(temp, path)?;
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use json::Checkpoint;
@@ -0,0 +1,184 @@
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+use super::{
+    checkpoint::{Checkpointer, Snapshot},
+    Callback, Progress,
+};
+use crate::{
+    agents::Agent,
+    envs::Env,
+    types::{Action, Observation, Reward, Transition},
+    Result,
+};
+
+/// Outcome of an episode, i.e. its cumulative reward and whether a callback requested to stop early.
+pub(crate) struct Outcome {
+    /// Cumulative reward of the episode.
+    pub reward: f64,
+    /// Whether the remaining episodes of the current phase should be skipped.
+    pub stop: bool,
+}
+
+/// Runs an episode of the given repetition, updating the agent if it learns.
+///
+/// Each step is passed to `record` together with the pseudo-regret of its action, if the environment
+/// has an oracle, which is computed before the action is performed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run_episode<A, R, S, P, V, G, E, F>(
+    i: usize,
+    j: usize,
+    agent: &mut G,
+    environment: &mut E,
+    rng: &mut Xoshiro256PlusPlus,
+    callbacks: &[&dyn Callback<A, R, S, G, E>],
+    is_learning: bool,
+    mut record: F,
+) -> Outcome
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+    G: Agent<A, R, S, P, V>,
+    E: Env<A, R, S>,
+    F: FnMut(&Transition<A, R, S>, Option<f64>),
+{
+    // Reset the environment and get its initial state.
+    let mut state = environment.reset(rng).get_state();
+    // Notify the start of the episode.
+    callbacks
+        .iter()
+        .for_each(|c| c.on_episode_start(i, j, agent, environment));
+    // Init the cumulative reward.
+    let (mut is_done, mut cum_reward) = (false, 0.);
+    // While the episode is not over ...
+    while !is_done {
+        // Notify the start of the step.
+        callbacks
+            .iter()
+            .for_each(|c| c.on_step_start(i, j, agent, environment, &state));
+        // ... get the action for the current state ...
+        let action = agent.call(&state, rng);
+        // ... compute its pseudo-regret, if known ...
+        let regret = environment.oracle().map(|oracle| oracle.regret(&action, &state));
+        // ... perform the action ...
+        let (reward, next_state);
+        (reward, next_state, is_done) = environment.call_mut(&action, rng);
+        let transition = Transition {
+            state,
+            action,
+            reward,
+            next_state,
+            is_done,
+        };
+        // ... update the agent, if learning ...
+        if is_learning {
+            agent.update(&transition, rng);
+        }
+        // ... and record the step.
+        cum_reward += transition.reward.as_();
+        record(&transition, regret);
+        // Notify the end of the step.
+        callbacks
+            .iter()
+            .for_each(|c| c.on_step_end(i, j, agent, environment, &transition));
+        state = transition.next_state;
+    }
+    // Notify the end of the episode, stopping early if requested.
+    let stop = callbacks.iter().fold(false, |stop, c| {
+        c.on_episode_end(i, j, agent, environment).is_break() | stop
+    });
+
+    Outcome {
+        reward: cum_reward,
+        stop,
+    }
+}
+
+/// Runs a repetition, i.e. the given number of learning episodes followed by the `finish` phase,
+/// e.g. test episodes, restoring and saving the snapshots of the repetition, if required.
+///
+/// Each learning step is passed to `record` together with the output of the repetition, the episode index
+/// and the pseudo-regret of its action, if known, see [`run_episode`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run_repetition<A, R, S, P, V, G, E, O, F, H>(
+    i: usize,
+    episodes: usize,
+    agent: &mut G,
+    environment: &mut E,
+    rng: &mut Xoshiro256PlusPlus,
+    callbacks: &[&dyn Callback<A, R, S, G, E>],
+    progress: &dyn Progress,
+    checkpoint: Option<&dyn Checkpointer<G, E, O>>,
+    output: O,
+    mut record: F,
+    finish: H,
+) -> Result<O>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+    G: Agent<A, R, S, P, V>,
+    E: Env<A, R, S>,
+    F: FnMut(&mut O, usize, &Transition<A, R, S>, Option<f64>),
+    H: FnOnce(&mut G, &mut E, &mut Xoshiro256PlusPlus, &mut O),
+{
+    let (mut start, mut output) = (0, output);
+    // Restore the repetition from its last snapshot, if any.
+    if let Some(snapshot) = checkpoint.map(|c| c.load(i)).transpose()?.flatten() {
+        (*agent, *environment, *rng) = (snapshot.agent, snapshot.environment, snapshot.rng);
+        (start, output) = (snapshot.episode, snapshot.output);
+        // Skip the repetition if completed.
+        if snapshot.done {
+            progress.repeat(i);
+            return Ok(output);
+        }
+    }
+    // Notify the start of the repetition.
+    callbacks.iter().for_each(|c| c.on_repeat_start(i, agent, environment));
+    // Perform the learning episodes ...
+    for j in start..episodes {
+        let outcome = run_episode(i, j, agent, environment, rng, callbacks, true, |t, regret| {
+            record(&mut output, j, t, regret)
+        });
+        // Update progress.
+        progress.episode(i, j, outcome.reward);
+        // Save a snapshot of the repetition, if required.
+        if let Some(checkpoint) = checkpoint.filter(|c| (j + 1) % c.every() == 0) {
+            checkpoint.save(
+                i,
+                Snapshot {
+                    episode: j + 1,
+                    done: false,
+                    agent,
+                    environment,
+                    rng: rng.clone(),
+                    output: &output,
+                },
+            )?;
+        }
+        if outcome.stop {
+            break;
+        }
+    }
+    // ... then the final phase.
+    finish(agent, environment, rng, &mut output);
+    // Notify the end of the repetition.
+    callbacks.iter().for_each(|c| c.on_repeat_end(i, agent, environment));
+    // Save the completed repetition, if required.
+    if let Some(checkpoint) = checkpoint {
+        checkpoint.save(
+            i,
+            Snapshot {
+                episode: episodes,
+                done: true,
+                agent,
+                environment,
+                rng: rng.clone(),
+                output: &output,
+            },
+        )?;
+    }
+    progress.repeat(i);
+
+    Ok(output)
+}
@@ -1,5 +1,29 @@
+mod callback;
+pub use callback::Callback;
+
+mod progress;
+pub use progress::{BarProgress, LogProgress, NoProgress, Progress};
+
+mod checkpoint;
+#[cfg(feature = "serde")]
+pub use checkpoint::Checkpoint;
+
+mod episode;
+
+mod repetition;
+pub use repetition::Repetition;
+
 mod session;
 pub use session::Session;
 
 mod train_test_session;
-pub use train_test_session::TrainTestSession;
+pub use train_test_session::{Evaluation, TrainTestSession};
+
+mod online_session;
+pub use online_session::OnlineSession;
+
+mod benchmark;
+pub use benchmark::Benchmark;
+
+mod sweep;
+pub use sweep::{Parameter, Parameters, Search, Sweep};
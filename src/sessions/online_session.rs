@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use polars::prelude::*;
+use rand::Rng;
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "serde")]
+use super::Checkpoint;
+use super::{
+    checkpoint::Checkpointer, episode::run_repetition, repetition::repeat, session::check_spaces, BarProgress,
+    Callback, Progress, Repetition, Session,
+};
+use crate::{
+    agents::Agent,
+    envs::Env,
+    types::{Action, Observation, Reward},
+    Result,
+};
+
+/// Steps recorded during a repetition of an online session.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Steps {
+    episode: Vec<u64>,
+    action: Vec<String>,
+    reward: Vec<f64>,
+    regret: Vec<f64>,
+}
+
+/// An online session, where an agent learns for `n` episodes while every step is recorded,
+/// repeating the learning process for `k` times.
+///
+/// The resulting data frame contains one row for each step, with the repetition, episode
+/// and step indices, the performed action and the obtained reward, where steps are counted
+/// from the beginning of each repetition. If the environment has an oracle, the pseudo-regret
+/// of the action and whether the action is optimal are recorded as well.
+///
+/// Each repetition is given an independent random number generator derived from a master seed,
+/// which is drawn from the given random number generator. By default, repetitions are executed
+/// sequentially, continuing to train the given agent, while independent runs are executed in parallel
+/// on reset clones of the agent and the environment, see [`Repetition`].
+pub struct OnlineSession {
+    episodes: usize,
+    repeat: usize,
+    repetition: Repetition,
+    threads: usize,
+    progress: Arc<dyn Progress>,
+}
+
+impl OnlineSession {
+    /// Constructs an online session.
+    ///
+    /// Repetitions are sequential by default, continuing to train the given agent,
+    /// hence running them in parallel requires `with_repetition(Repetition::Independent)`.
+    pub fn new(episodes: usize, repeat: usize) -> Self {
+        Self {
+            episodes,
+            repeat,
+            repetition: Default::default(),
+            threads: 0,
+            progress: Arc::new(BarProgress::default()),
+        }
+    }
+
+    /// Sets the semantics of the repetitions.
+    pub fn with_repetition(mut self, repetition: Repetition) -> Self {
+        self.repetition = repetition;
+
+        self
+    }
+
+    /// Sets the number of threads used to execute the repetitions, where zero selects the number of CPUs.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+
+        self
+    }
+
+    /// Sets the progress reporting, e.g. [`NoProgress`](super::NoProgress) to silence it.
+    pub fn with_progress<P>(mut self, progress: P) -> Self
+    where
+        P: Progress + 'static,
+    {
+        self.progress = Arc::new(progress);
+
+        self
+    }
+
+    /// Executes the session, saving and restoring the snapshots of the repetitions, if required.
+    fn run<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+        checkpoint: Option<&dyn Checkpointer<G, E, Steps>>,
+    ) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        T: Rng + ?Sized,
+    {
+        // Check the action- and state-space.
+        check_spaces(agent, environment)?;
+        // Notify the start of the session.
+        callbacks.iter().for_each(|c| c.on_session_start());
+        // Initialize progress.
+        self.progress.start(self.repeat, self.episodes);
+        // For each repetition ...
+        let repetitions = repeat(
+            agent,
+            environment,
+            self.repeat,
+            self.repetition,
+            self.threads,
+            rng,
+            |i, agent, environment, rng| {
+                // Record every learning step, without further phases.
+                run_repetition(
+                    i,
+                    self.episodes,
+                    agent,
+                    environment,
+                    rng,
+                    callbacks,
+                    &*self.progress,
+                    checkpoint,
+                    Steps::default(),
+                    |steps, j, transition, regret| {
+                        steps.episode.push(j as u64);
+                        steps.action.push(format!("{:?}", transition.action));
+                        steps.reward.push(transition.reward.as_());
+                        steps.regret.extend(regret);
+                    },
+                    |_, _, _, _| {},
+                )
+            },
+        );
+        // Close progress.
+        self.progress.finish();
+        // Notify the end of the session.
+        callbacks.iter().for_each(|c| c.on_session_end());
+        // Propagate the error of the thread pool or the first error of the repetitions, if any.
+        let repetitions = repetitions?.into_iter().collect::<Result<Vec<_>>>()?;
+
+        // Collect data in (repetition, step) order.
+        let (mut reps, mut epis, mut step) = (vec![], vec![], vec![]);
+        let (mut actn, mut rewd, mut regr) = (vec![], vec![], vec![]);
+        for (i, steps) in repetitions.into_iter().enumerate() {
+            reps.extend(std::iter::repeat_n(i as u64, steps.episode.len()));
+            step.extend(0..steps.episode.len() as u64);
+            epis.extend(steps.episode);
+            actn.extend(steps.action);
+            rewd.extend(steps.reward);
+            regr.extend(steps.regret);
+        }
+        let optm: Vec<_> = regr.iter().map(|&r| r <= 0.).collect();
+
+        // Cast data to polars DataFrame.
+        let reps = ChunkedArray::<UInt64Type>::from_vec("reps", reps).into_series();
+        let epis = ChunkedArray::<UInt64Type>::from_vec("episode", epis).into_series();
+        let step = ChunkedArray::<UInt64Type>::from_vec("step", step).into_series();
+        let actn = Series::new("action", actn);
+        let rewd = ChunkedArray::<Float64Type>::from_vec("reward", rewd).into_series();
+        let mut data = vec![reps, epis, step, actn, rewd];
+        // Add the pseudo-regret and whether the action is optimal, if known.
+        if environment.oracle().is_some() {
+            data.push(ChunkedArray::<Float64Type>::from_vec("regret", regr).into_series());
+            data.push(Series::new("optimal", optm));
+        }
+        Ok(DataFrame::new(data)?)
+    }
+}
+
+impl Session for OnlineSession {
+    fn try_call_with_callbacks<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+    ) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        T: Rng + ?Sized,
+    {
+        self.run(agent, environment, rng, callbacks, None)
+    }
+
+    #[cfg(feature = "serde")]
+    fn call_with_checkpoint<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+        checkpoint: &Checkpoint,
+    ) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send + Serialize + DeserializeOwned,
+        E: Env<A, R, S> + Send + Serialize + DeserializeOwned,
+        T: Rng + ?Sized,
+    {
+        self.run(agent, environment, rng, callbacks, Some(checkpoint))
+    }
+}
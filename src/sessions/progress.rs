@@ -0,0 +1,197 @@
+use std::{
+    fmt::Display,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Definition of the progress reporting of an experiment session.
+///
+/// The session notifies its start, the end of each learning episode together with its cumulative
+/// reward, the end of each repetition and its end. Since repetitions may be executed in parallel,
+/// methods take a shared reference, hence progress with state should rely on interior mutability.
+/// Every method does nothing by default.
+#[allow(unused_variables)]
+pub trait Progress: Send + Sync {
+    /// Invoked when the session starts, given the number of repetitions and of learning episodes per repetition.
+    fn start(&self, repeat: usize, episodes: usize) {}
+
+    /// Invoked when a learning episode ends, given the repetition and episode indices and the cumulative reward.
+    fn episode(&self, repeat: usize, episode: usize, reward: f64) {}
+
+    /// Invoked when a repetition ends, given its index.
+    fn repeat(&self, repeat: usize) {}
+
+    /// Invoked when the session ends.
+    fn finish(&self) {}
+}
+
+/// Nested progress of a session, i.e. completed repetitions and episodes, running mean reward and ETA.
+#[derive(Clone, Debug)]
+struct Tracker {
+    start: Instant,
+    repeat: (usize, usize),
+    episode: (usize, usize),
+    reward: f64,
+}
+
+impl Tracker {
+    fn new(repeat: usize, episodes: usize) -> Self {
+        Self {
+            start: Instant::now(),
+            repeat: (0, repeat),
+            episode: (0, repeat * episodes),
+            reward: 0.,
+        }
+    }
+
+    fn episode(&mut self, reward: f64) {
+        self.episode.0 += 1;
+        self.reward += reward;
+    }
+
+    fn repeat(&mut self) {
+        self.repeat.0 += 1;
+    }
+
+    /// Mean cumulative reward of the completed episodes.
+    fn mean_reward(&self) -> f64 {
+        match self.episode.0 {
+            0 => f64::NAN,
+            n => self.reward / n as f64,
+        }
+    }
+
+    /// Estimated time to completion, based on the completed episodes.
+    fn eta(&self) -> Duration {
+        let (done, total) = self.episode;
+        match done {
+            0 => Duration::ZERO,
+            _ => self
+                .start
+                .elapsed()
+                .mul_f64(total.saturating_sub(done) as f64 / done as f64),
+        }
+    }
+}
+
+impl Display for Tracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "repeat {}/{}, episode {}/{}, mean reward {:.4}, eta {}s",
+            self.repeat.0,
+            self.repeat.1,
+            self.episode.0,
+            self.episode.1,
+            self.mean_reward(),
+            self.eta().as_secs()
+        )
+    }
+}
+
+/// Progress reported with an interactive progress bar.
+#[derive(Default)]
+pub struct BarProgress {
+    state: Mutex<Option<(ProgressBar, Tracker)>>,
+}
+
+impl Progress for BarProgress {
+    fn start(&self, repeat: usize, episodes: usize) {
+        // Initialize progress bar.
+        let bar = ProgressBar::new((repeat * episodes) as u64);
+        bar.set_style(ProgressStyle::default_bar().template("{bar:40} {pos}/{len} [{elapsed}<{eta}] {msg}"));
+        let tracker = Tracker::new(repeat, episodes);
+        bar.set_message(format!("repeat 0/{}", repeat));
+
+        *self.state.lock().expect("Unable to lock progress") = Some((bar, tracker));
+    }
+
+    fn episode(&self, _repeat: usize, _episode: usize, reward: f64) {
+        if let Some((bar, tracker)) = &mut *self.state.lock().expect("Unable to lock progress") {
+            tracker.episode(reward);
+            bar.set_message(format!(
+                "repeat {}/{}, mean reward {:.4}",
+                tracker.repeat.0,
+                tracker.repeat.1,
+                tracker.mean_reward()
+            ));
+            bar.inc(1);
+        }
+    }
+
+    fn repeat(&self, _repeat: usize) {
+        if let Some((_, tracker)) = &mut *self.state.lock().expect("Unable to lock progress") {
+            tracker.repeat();
+        }
+    }
+
+    fn finish(&self) {
+        // Close progress bar.
+        if let Some((bar, _)) = self.state.lock().expect("Unable to lock progress").take() {
+            bar.finish();
+        }
+    }
+}
+
+/// Progress reported with plain log lines on the standard error, at most once per given interval.
+#[derive(Debug)]
+pub struct LogProgress {
+    interval: Duration,
+    state: Mutex<Option<(Instant, Tracker)>>,
+}
+
+impl LogProgress {
+    /// Constructs a log progress given the minimum interval between log lines.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            state: Default::default(),
+        }
+    }
+}
+
+impl Default for LogProgress {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(10))
+    }
+}
+
+impl Progress for LogProgress {
+    fn start(&self, repeat: usize, episodes: usize) {
+        let tracker = Tracker::new(repeat, episodes);
+        eprintln!("{}", tracker);
+
+        *self.state.lock().expect("Unable to lock progress") = Some((Instant::now(), tracker));
+    }
+
+    fn episode(&self, _repeat: usize, _episode: usize, reward: f64) {
+        if let Some((last, tracker)) = &mut *self.state.lock().expect("Unable to lock progress") {
+            tracker.episode(reward);
+            // Log the progress if the interval has elapsed.
+            if last.elapsed() >= self.interval {
+                *last = Instant::now();
+                eprintln!("{}", tracker);
+            }
+        }
+    }
+
+    fn repeat(&self, _repeat: usize) {
+        if let Some((_, tracker)) = &mut *self.state.lock().expect("Unable to lock progress") {
+            tracker.repeat();
+        }
+    }
+
+    fn finish(&self) {
+        if let Some((_, tracker)) = self.state.lock().expect("Unable to lock progress").take() {
+            eprintln!("{}", tracker);
+        }
+    }
+}
+
+/// Progress not reported at all.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {}
@@ -0,0 +1,102 @@
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use rayon::prelude::*;
+
+use crate::{
+    agents::Agent,
+    envs::Env,
+    types::{Action, Observation, Reward},
+    Result,
+};
+
+/// Semantics of the repetitions of an experiment session.
+///
+/// The default is continual learning, so that the given agent is trained by the session,
+/// as it was before independent runs were introduced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Repetition {
+    /// Independent runs, where each repetition starts from a reset clone of the given agent and environment.
+    /// Repetitions are executed in parallel and the given agent and environment are left untouched.
+    Independent,
+    /// Continual learning, where each repetition continues from the agent of the previous one.
+    /// Repetitions are executed sequentially on the given agent and environment.
+    #[default]
+    Continual,
+}
+
+/// Derives an independent random number generator for each repetition from a master seed.
+///
+/// The generators are non-overlapping streams obtained by jumping ahead the master generator,
+/// hence the `i`-th generator depends only on the master seed and `i`.
+pub(crate) fn streams(seed: u64, repeat: usize) -> Vec<Xoshiro256PlusPlus> {
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+
+    (0..repeat)
+        .map(|_| {
+            let stream = rng.clone();
+            rng.jump();
+            stream
+        })
+        .collect()
+}
+
+/// Executes the repetitions of an experiment session given their semantics,
+/// returning the results in repetition order, failing if the thread pool cannot be built.
+///
+/// The master seed is drawn from the given random number generator, then each repetition
+/// is given an independent stream, so that results do not depend on the number of threads.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn repeat<A, R, S, P, V, G, E, T, F, O>(
+    agent: &mut G,
+    environment: &mut E,
+    repeat: usize,
+    repetition: Repetition,
+    threads: usize,
+    rng: &mut T,
+    f: F,
+) -> Result<Vec<O>>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+    G: Agent<A, R, S, P, V> + Clone + Send,
+    E: Env<A, R, S> + Send,
+    T: Rng + ?Sized,
+    F: Fn(usize, &mut G, &mut E, &mut Xoshiro256PlusPlus) -> O + Send + Sync,
+    O: Send,
+{
+    let streams = streams(rng.gen(), repeat);
+
+    match repetition {
+        Repetition::Independent => {
+            // Initialize the thread pool, where zero threads selects the default.
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build()?;
+            // Clone and reset the agent and the environment for each repetition.
+            let jobs: Vec<_> = streams
+                .into_iter()
+                .map(|mut stream| {
+                    let mut agent = agent.clone();
+                    let mut environment = environment.clone();
+                    agent.reset();
+                    environment.reset(&mut stream);
+
+                    (agent, environment, stream)
+                })
+                .enumerate()
+                .collect();
+
+            Ok(pool.install(|| {
+                jobs.into_par_iter()
+                    .map(|(i, (mut agent, mut environment, mut stream))| {
+                        f(i, &mut agent, &mut environment, &mut stream)
+                    })
+                    .collect()
+            }))
+        }
+        Repetition::Continual => Ok(streams
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut stream)| f(i, agent, environment, &mut stream))
+            .collect()),
+    }
+}
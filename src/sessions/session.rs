@@ -1,25 +1,143 @@
-use polars::prelude::*;
+use polars::prelude::DataFrame;
 use rand::Rng;
 
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::Callback;
+#[cfg(feature = "serde")]
+use super::Checkpoint;
 use crate::{
     agents::Agent,
     envs::Env,
-    policies::Policy,
-    types::{Action, Reward, State},
-    values::StateActionValue,
+    spaces::Space,
+    types::{Action, Observation, Reward},
+    Error, Result,
 };
 
 /// Definition of agent-environment experiment session.
+///
+/// The agent must be `Clone + Send` and the environment must be `Send`, since [`Repetition::Independent`]
+/// runs are executed on reset clones of the given agent and environment, moved to other threads.
+/// Agents and environments of this crate satisfy these bounds, while custom ones holding e.g. `Rc`
+/// or `RefCell` must be ported to their thread-safe counterparts.
+///
+/// [`Repetition::Independent`]: super::Repetition::Independent
 pub trait Session {
     /// Execute the experiment session.
     fn call<A, R, S, P, V, G, E, T>(&self, agent: &mut G, environment: &mut E, rng: &mut T) -> DataFrame
     where
         A: Action,
         R: Reward,
-        S: State,
-        P: Policy,
-        V: StateActionValue<A, R, S>,
-        G: Agent<A, R, S, P, V>,
-        E: Env<A, R, S>,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        T: Rng + ?Sized,
+    {
+        self.try_call(agent, environment, rng)
+            .expect("Unable to execute session")
+    }
+
+    /// Execute the experiment session, failing if the agent and the environment are incompatible.
+    fn try_call<A, R, S, P, V, G, E, T>(&self, agent: &mut G, environment: &mut E, rng: &mut T) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        T: Rng + ?Sized,
+    {
+        self.try_call_with_callbacks(agent, environment, rng, &[])
+    }
+
+    /// Execute the experiment session, invoking the given callbacks.
+    fn call_with_callbacks<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+    ) -> DataFrame
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        T: Rng + ?Sized,
+    {
+        self.try_call_with_callbacks(agent, environment, rng, callbacks)
+            .expect("Unable to execute session")
+    }
+
+    /// Execute the experiment session, invoking the given callbacks,
+    /// failing if the agent and the environment are incompatible.
+    fn try_call_with_callbacks<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+    ) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        T: Rng + ?Sized;
+
+    /// Execute the experiment session, invoking the given callbacks and saving a snapshot of each
+    /// repetition to the given checkpoint, resuming the repetitions from their last snapshot, if any.
+    /// Fails if the agent and the environment are incompatible, or the checkpoint cannot be read or written.
+    #[cfg(feature = "serde")]
+    fn call_with_checkpoint<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+        checkpoint: &Checkpoint,
+    ) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send + Serialize + DeserializeOwned,
+        E: Env<A, R, S> + Send + Serialize + DeserializeOwned,
         T: Rng + ?Sized;
 }
+
+/// Checks that the agent and the environment have the same non-empty action-space and the same state-space.
+pub(crate) fn check_spaces<A, R, S, P, V, G, E>(agent: &G, environment: &E) -> Result<()>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+    G: Agent<A, R, S, P, V>,
+    E: Env<A, R, S>,
+{
+    if agent.action_space().is_empty() {
+        return Err(Error::EmptyActionSpace);
+    }
+    if !same_space(agent.action_space(), environment.action_space()) {
+        return Err(Error::SpaceMismatch("actions"));
+    }
+    if !same_space(agent.state_space(), environment.state_space()) {
+        return Err(Error::SpaceMismatch("states"));
+    }
+
+    Ok(())
+}
+
+/// Checks that two spaces have the same elements, if both enumerable, or are equal otherwise,
+/// e.g. continuous spaces with the same bounds.
+fn same_space<T>(x: &dyn Space<T>, y: &dyn Space<T>) -> bool {
+    match (x.iter(), y.iter()) {
+        // Enumerations have no duplicates, hence same size and inclusion imply equality.
+        (Some(mut i), Some(_)) => x.len() == y.len() && i.all(|e| y.contains(&e)),
+        (None, None) => x.eq_dyn(y),
+        _ => false,
+    }
+}
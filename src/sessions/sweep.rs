@@ -0,0 +1,261 @@
+use std::collections::BTreeMap;
+
+use polars::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+use rayon::prelude::*;
+
+use super::{repetition::streams, Session};
+use crate::{
+    agents::Agent,
+    envs::Env,
+    results::{Interval, Summary},
+    types::{Action, Observation, Reward},
+    Error, Result,
+};
+
+/// Hyperparameters of an agent, indexed by name.
+pub type Parameters = BTreeMap<String, f64>;
+
+/// Domain of a hyperparameter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Parameter {
+    /// Finite set of values, either enumerated or sampled uniformly.
+    Values(Vec<f64>),
+    /// Uniform distribution over the given interval.
+    Uniform(f64, f64),
+    /// Log-uniform distribution over the given positive interval.
+    LogUniform(f64, f64),
+}
+
+impl Parameter {
+    /// Samples a value of the hyperparameter, failing if there are no values to sample from.
+    fn sample<T>(&self, rng: &mut T) -> Result<f64>
+    where
+        T: Rng + ?Sized,
+    {
+        match self {
+            Self::Values(values) => values
+                .choose(rng)
+                .copied()
+                .ok_or_else(|| Error::InvalidParameter("Parameter values must be non-empty".into())),
+            Self::Uniform(low, high) => Ok(low + (high - low) * rng.gen::<f64>()),
+            Self::LogUniform(low, high) => Ok((low.ln() + (high.ln() - low.ln()) * rng.gen::<f64>()).exp()),
+        }
+    }
+}
+
+/// Search strategy of a hyperparameter sweep.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Search {
+    /// Exhaustive search over the cartesian product of the hyperparameter values.
+    #[default]
+    Grid,
+    /// Random search over the given number of sampled configurations.
+    Random(usize),
+}
+
+/// Agent factory given its hyperparameters.
+type AgentFactory<G> = Box<dyn Fn(&Parameters) -> G + Send + Sync>;
+
+/// A hyperparameter sweep, where agents built from each configuration are run through a session
+/// with multiple seeds.
+///
+/// The score of each run is the mean of the metric column of the session results, e.g. the `reward`
+/// of the test episodes. Each seed is shared by all the configurations, so that they are compared on
+/// common random numbers, and the seeds are drawn from the given random number generator. The resulting
+/// data frame contains one row for each configuration, ranked by mean score, with the `rank` and `config`
+/// indices, the hyperparameters and the summary statistics of the score across seeds, see [`Summary`].
+///
+/// Runs are executed sequentially by default, while parallel runs should silence the progress
+/// of the session, see [`NoProgress`](super::NoProgress).
+pub struct Sweep<G, E> {
+    parameters: Vec<(String, Parameter)>,
+    agent: AgentFactory<G>,
+    environment: Box<dyn Fn() -> E + Send + Sync>,
+    search: Search,
+    seeds: usize,
+    metric: (String, bool),
+    interval: Interval,
+    threads: usize,
+}
+
+impl<G, E> Sweep<G, E> {
+    /// Constructs a sweep given the agent factory and the environment factory.
+    pub fn new<F, H>(agent: F, environment: H) -> Self
+    where
+        F: Fn(&Parameters) -> G + Send + Sync + 'static,
+        H: Fn() -> E + Send + Sync + 'static,
+    {
+        Self {
+            parameters: vec![],
+            agent: Box::new(agent),
+            environment: Box::new(environment),
+            search: Default::default(),
+            seeds: 1,
+            metric: ("reward".into(), true),
+            interval: Default::default(),
+            threads: 1,
+        }
+    }
+
+    /// Adds a named hyperparameter given its domain.
+    pub fn with_parameter<N>(mut self, name: N, parameter: Parameter) -> Self
+    where
+        N: Into<String>,
+    {
+        self.parameters.push((name.into(), parameter));
+
+        self
+    }
+
+    /// Sets the search strategy.
+    pub fn with_search(mut self, search: Search) -> Self {
+        self.search = search;
+
+        self
+    }
+
+    /// Sets the number of seeds of each configuration.
+    pub fn with_seeds(mut self, seeds: usize) -> Self {
+        assert!(seeds > 0, "Number of seeds must be positive");
+        self.seeds = seeds;
+
+        self
+    }
+
+    /// Sets the metric column of the session results and whether it should be maximized or minimized.
+    pub fn with_metric<N>(mut self, metric: N, maximize: bool) -> Self
+    where
+        N: Into<String>,
+    {
+        self.metric = (metric.into(), maximize);
+
+        self
+    }
+
+    /// Sets the confidence interval of the mean score.
+    pub fn with_interval(mut self, interval: Interval) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Sets the number of threads used to execute the runs, where zero selects the number of CPUs.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+
+        self
+    }
+
+    /// Generates the configurations to evaluate,
+    /// failing if grid search is given parameters without finite values.
+    fn configurations<T>(&self, rng: &mut T) -> Result<Vec<Parameters>>
+    where
+        T: Rng + ?Sized,
+    {
+        match self.search {
+            Search::Grid => self
+                .parameters
+                .iter()
+                .try_fold(vec![Parameters::new()], |configs, (name, p)| {
+                    let values = match p {
+                        Parameter::Values(values) => values,
+                        _ => {
+                            return Err(Error::InvalidParameter(format!(
+                                "Grid search requires parameters with finite values, got {:?} for {}",
+                                p, name
+                            )))
+                        }
+                    };
+                    Ok(configs
+                        .into_iter()
+                        .flat_map(|config| {
+                            values.iter().map(move |&v| {
+                                let mut config = config.clone();
+                                config.insert(name.clone(), v);
+                                config
+                            })
+                        })
+                        .collect())
+                }),
+            Search::Random(n) => (0..n)
+                .map(|_| {
+                    self.parameters
+                        .iter()
+                        .map(|(name, p)| Ok((name.clone(), p.sample(rng)?)))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Executes the sweep with the given session.
+    pub fn call<A, R, S, P, V, X, T>(&self, session: &X, rng: &mut T) -> DataFrame
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        X: Session + Sync,
+        T: Rng + ?Sized,
+    {
+        self.try_call(session, rng).expect("Unable to execute sweep")
+    }
+
+    /// Executes the sweep with the given session, failing if the hyperparameters are invalid,
+    /// any session fails or the metric column is missing or not numeric.
+    pub fn try_call<A, R, S, P, V, X, T>(&self, session: &X, rng: &mut T) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        X: Session + Sync,
+        T: Rng + ?Sized,
+    {
+        // Generate the configurations and the seeds shared by them.
+        let configs = self.configurations(rng)?;
+        let seeds = streams(rng.gen(), self.seeds);
+        // Initialize the thread pool, where zero threads selects the default.
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(self.threads).build()?;
+        // For each configuration and seed, compute the score of the run.
+        let jobs: Vec<_> = (0..configs.len())
+            .flat_map(|i| (0..self.seeds).map(move |j| (i, j)))
+            .collect();
+        let scores: Vec<f64> = pool.install(|| {
+            jobs.par_iter()
+                .map(|&(i, j)| {
+                    let (mut agent, mut environment) = ((self.agent)(&configs[i]), (self.environment)());
+                    let data = session.try_call(&mut agent, &mut environment, &mut seeds[j].clone())?;
+                    let metric = data.column(&self.metric.0)?.cast(&DataType::Float64)?;
+
+                    Ok(metric.mean().unwrap_or(f64::NAN))
+                })
+                .collect::<Result<_>>()
+        })?;
+
+        // Summarize the scores of each configuration across seeds.
+        let config: Vec<_> = jobs.iter().map(|&(i, _)| i as u64).collect();
+        let config = ChunkedArray::<UInt64Type>::from_vec("config", config).into_series();
+        let score = ChunkedArray::<Float64Type>::from_vec("score", scores).into_series();
+        let data = DataFrame::new(vec![config, score])?;
+        let mut summary = Summary::new(["config"], ["score"])
+            .with_quantiles([])
+            .with_interval(self.interval)
+            .try_call(&data, rng)?;
+        // Add the hyperparameters of each configuration.
+        for (k, (name, _)) in self.parameters.iter().enumerate() {
+            let values: Vec<_> = configs.iter().map(|c| c[name]).collect();
+            let values = ChunkedArray::<Float64Type>::from_vec(name, values).into_series();
+            summary.insert_at_idx(k + 1, values)?;
+        }
+        // Rank the configurations by mean score.
+        let mut summary = summary.sort(["score_mean"], self.metric.1)?;
+        let rank: Vec<_> = (1..=summary.height() as u64).collect();
+        summary.insert_at_idx(0, ChunkedArray::<UInt64Type>::from_vec("rank", rank).into_series())?;
+
+        Ok(summary)
+    }
+}
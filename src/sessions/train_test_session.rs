@@ -1,117 +1,278 @@
-use std::collections::HashSet;
+use std::sync::Arc;
 
-use indicatif::ProgressBar;
 use polars::prelude::*;
 use rand::Rng;
 
-use super::Session;
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "serde")]
+use super::Checkpoint;
+use super::{
+    checkpoint::Checkpointer,
+    episode::{run_episode, run_repetition},
+    repetition::repeat,
+    session::check_spaces,
+    BarProgress, Callback, Progress, Repetition, Session,
+};
 use crate::{
     agents::Agent,
     envs::Env,
-    policies::Policy,
-    types::{Action, Reward, State},
-    values::StateActionValue,
+    types::{Action, Observation, Reward},
+    Result,
 };
 
+/// Policy followed by the agent during test episodes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Evaluation {
+    /// Test with the exploring (on-policy) behaviour of the agent.
+    OnPolicy,
+    /// Test with the agent in evaluation mode.
+    #[default]
+    Greedy,
+    /// Test with both, i.e. perform the test episodes once for each mode.
+    Both,
+}
+
+impl Evaluation {
+    /// Gets the evaluation modes of the agent to test with.
+    fn modes(&self) -> &'static [bool] {
+        match self {
+            Self::OnPolicy => &[false],
+            Self::Greedy => &[true],
+            Self::Both => &[false, true],
+        }
+    }
+}
+
+/// Tests recorded during a repetition of a train-test session, i.e. the test index, the evaluation mode,
+/// the cumulative reward and pseudo-regret and the percent of optimal actions.
+type Tests = Vec<(usize, bool, f64, f64, f64)>;
+
 /// A train-test session, where an agent is trained for `n` episodes,
 /// then tested for `m` episodes, repeating the train-test process for `k` times.
+///
+/// During test episodes the agent is set in evaluation mode by default, see [`Evaluation`].
+/// The resulting data frame contains one row for each test episode, with the repetition and test
+/// indices, the evaluation mode (either `on-policy` or `greedy`) and the cumulative reward.
+/// If the environment has an oracle, the cumulative pseudo-regret and the percent of optimal
+/// actions are recorded as well.
+///
+/// Each repetition is given an independent random number generator derived from a master seed,
+/// which is drawn from the given random number generator. By default, repetitions are executed
+/// sequentially, continuing to train the given agent, while independent runs are executed in parallel
+/// on reset clones of the agent and the environment, see [`Repetition`].
 pub struct TrainTestSession {
     train: usize,
     test: usize,
     repeat: usize,
+    repetition: Repetition,
+    evaluation: Evaluation,
+    threads: usize,
+    progress: Arc<dyn Progress>,
 }
 
 impl TrainTestSession {
     /// Constructs a train-test session.
+    ///
+    /// Repetitions are sequential by default, continuing to train the given agent,
+    /// hence running them in parallel requires `with_repetition(Repetition::Independent)`.
     pub fn new(train: usize, test: usize, repeat: usize) -> Self {
-        Self { train, test, repeat }
+        Self {
+            train,
+            test,
+            repeat,
+            repetition: Default::default(),
+            evaluation: Default::default(),
+            threads: 0,
+            progress: Arc::new(BarProgress::default()),
+        }
     }
-}
 
-impl Session for TrainTestSession {
-    fn call<A, R, S, P, V, G, E, T>(&self, agent: &mut G, environment: &mut E, rng: &mut T) -> DataFrame
+    /// Sets the semantics of the repetitions.
+    pub fn with_repetition(mut self, repetition: Repetition) -> Self {
+        self.repetition = repetition;
+
+        self
+    }
+
+    /// Sets the policy followed by the agent during test episodes.
+    pub fn with_evaluation(mut self, evaluation: Evaluation) -> Self {
+        self.evaluation = evaluation;
+
+        self
+    }
+
+    /// Sets the number of threads used to execute the repetitions, where zero selects the number of CPUs.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+
+        self
+    }
+
+    /// Sets the progress reporting, e.g. [`NoProgress`](super::NoProgress) to silence it.
+    pub fn with_progress<P>(mut self, progress: P) -> Self
+    where
+        P: Progress + 'static,
+    {
+        self.progress = Arc::new(progress);
+
+        self
+    }
+
+    /// Executes the session, saving and restoring the snapshots of the repetitions, if required.
+    fn run<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+        checkpoint: Option<&dyn Checkpointer<G, E, Tests>>,
+    ) -> Result<DataFrame>
     where
         A: Action,
         R: Reward,
-        S: State,
-        P: Policy,
-        V: StateActionValue<A, R, S>,
-        G: Agent<A, R, S, P, V>,
-        E: Env<A, R, S>,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
         T: Rng + ?Sized,
     {
-        // Assert same action- and state-space.
-        assert_eq!(
-            HashSet::<&A>::from_iter(agent.actions_iter()),
-            HashSet::<&A>::from_iter(environment.actions_iter()),
-            "Agent and environment have different actions-space"
-        );
-        assert_eq!(
-            HashSet::<&S>::from_iter(agent.states_iter()),
-            HashSet::<&S>::from_iter(environment.states_iter()),
-            "Agent and environment have different states-space"
+        // Check the action- and state-space.
+        check_spaces(agent, environment)?;
+        // Notify the start of the session.
+        callbacks.iter().for_each(|c| c.on_session_start());
+        // Initialize progress.
+        self.progress.start(self.repeat, self.train);
+        // For each fold ...
+        let folds = repeat(
+            agent,
+            environment,
+            self.repeat,
+            self.repetition,
+            self.threads,
+            rng,
+            |i, agent, environment, rng| {
+                // Perform n train episodes, recording nothing, then ...
+                run_repetition(
+                    i,
+                    self.train,
+                    agent,
+                    environment,
+                    rng,
+                    callbacks,
+                    &*self.progress,
+                    checkpoint,
+                    Tests::with_capacity(self.test * self.evaluation.modes().len()),
+                    |_, _, _, _| {},
+                    |agent, environment, rng, tests| {
+                        // ... perform m test episodes for each evaluation mode.
+                        for &is_eval in self.evaluation.modes() {
+                            // Set the evaluation mode of the agent.
+                            agent.set_eval(is_eval);
+                            for j in 0..self.test {
+                                // Accumulate the pseudo-regret and count the optimal actions, if known.
+                                let (mut cum_regret, mut optimal, mut steps) = (0., 0, 0);
+                                let outcome =
+                                    run_episode(i, j, agent, environment, rng, callbacks, false, |_, regret| {
+                                        if let Some(regret) = regret {
+                                            cum_regret += regret;
+                                            optimal += (regret <= 0.) as usize;
+                                        }
+                                        steps += 1;
+                                    });
+                                // Record the cumulative reward, pseudo-regret and percent of optimal actions.
+                                tests.push((j, is_eval, outcome.reward, cum_regret, optimal as f64 / steps as f64));
+                                if outcome.stop {
+                                    break;
+                                }
+                            }
+                        }
+                        // Restore the training mode of the agent.
+                        agent.set_eval(false);
+                    },
+                )
+            },
         );
-        // Allocate memory for data collection.
-        let capacity = self.repeat * self.test;
-        let mut rewd = Vec::with_capacity(capacity);
-        let mut test = Vec::with_capacity(capacity);
+        // Close progress.
+        self.progress.finish();
+        // Notify the end of the session.
+        callbacks.iter().for_each(|c| c.on_session_end());
+        // Propagate the error of the thread pool or the first error of the repetitions, if any.
+        let folds = folds?.into_iter().collect::<Result<Vec<_>>>()?;
+
+        // Collect data in (fold, test) order.
+        let capacity = folds.iter().map(Vec::len).sum();
         let mut reps = Vec::with_capacity(capacity);
-        // Initialize progress bar.
-        let progress = ProgressBar::new((self.repeat * self.train) as u64);
-        // For each fold ...
-        for i in 0..self.repeat {
-            // ... perform n train episodes, then ...
-            for _ in 0..self.train {
-                // Declare future reward.
-                let mut reward;
-                // Reset the environment and get its initial state.
-                let mut state = environment.reset().get_state();
-                // Set is_done flag to false.
-                let mut is_done = false;
-                // While the episode is not over ...
-                while !is_done {
-                    // ... get the action for the current state ...
-                    let action = agent.call(&state, rng);
-                    // ... perform the action ...
-                    (reward, state, is_done) = environment.call_mut(&action, rng);
-                    // ... update the agent.
-                    agent.update(&action, &reward, &state, is_done);
-                }
-                // Update progress.
-                progress.inc(1);
-            }
-            // ... perform m test episodes.
-            for j in 0..self.test {
-                // Reset the environment and get its initial state.
-                let mut state = environment.reset().get_state();
-                // Set is_done flag to false.
-                let mut is_done = false;
-                // Init the cumulative reward.
-                let mut cum_reward = 0.;
-                // Declare reward.
-                let mut reward;
-                // While the episode is not over ...
-                while !is_done {
-                    // ... get the action for the current state ...
-                    let action = agent.call(&state, rng);
-                    // ... perform the action ...
-                    (reward, state, is_done) = environment.call_mut(&action, rng);
-                    // ... update the cumulative reward.
-                    cum_reward += reward.as_();
-                }
-                // Record the cumulative reward.
-                rewd.push(cum_reward);
-                test.push(j as u64);
+        let mut test = Vec::with_capacity(capacity);
+        let mut mode = Vec::with_capacity(capacity);
+        let mut rewd = Vec::with_capacity(capacity);
+        let mut regr = Vec::with_capacity(capacity);
+        let mut optm = Vec::with_capacity(capacity);
+        for (i, fold) in folds.into_iter().enumerate() {
+            for (j, is_eval, reward, regret, optimal) in fold {
                 reps.push(i as u64);
+                test.push(j as u64);
+                mode.push(match is_eval {
+                    false => "on-policy",
+                    true => "greedy",
+                });
+                rewd.push(reward);
+                regr.push(regret);
+                optm.push(optimal);
             }
         }
-        // Close progress.
-        progress.finish();
 
         // Cast data to polars DataFrame.
         let rewd = ChunkedArray::<Float64Type>::from_vec("reward", rewd).into_series();
         let test = ChunkedArray::<UInt64Type>::from_vec("test", test).into_series();
         let reps = ChunkedArray::<UInt64Type>::from_vec("reps", reps).into_series();
-        DataFrame::new(vec![reps, test, rewd]).expect("Unable to cast collected data to DataFrame")
+        let mode = Series::new("mode", mode);
+        let mut data = vec![reps, test, mode, rewd];
+        // Add the pseudo-regret and the percent of optimal actions, if known.
+        if environment.oracle().is_some() {
+            data.push(ChunkedArray::<Float64Type>::from_vec("regret", regr).into_series());
+            data.push(ChunkedArray::<Float64Type>::from_vec("optimal", optm).into_series());
+        }
+        Ok(DataFrame::new(data)?)
+    }
+}
+
+impl Session for TrainTestSession {
+    fn try_call_with_callbacks<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+    ) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send,
+        E: Env<A, R, S> + Send,
+        T: Rng + ?Sized,
+    {
+        self.run(agent, environment, rng, callbacks, None)
+    }
+
+    #[cfg(feature = "serde")]
+    fn call_with_checkpoint<A, R, S, P, V, G, E, T>(
+        &self,
+        agent: &mut G,
+        environment: &mut E,
+        rng: &mut T,
+        callbacks: &[&dyn Callback<A, R, S, G, E>],
+        checkpoint: &Checkpoint,
+    ) -> Result<DataFrame>
+    where
+        A: Action,
+        R: Reward,
+        S: Observation,
+        G: Agent<A, R, S, P, V> + Clone + Send + Serialize + DeserializeOwned,
+        E: Env<A, R, S> + Send + Serialize + DeserializeOwned,
+        T: Rng + ?Sized,
+    {
+        self.run(agent, environment, rng, callbacks, Some(checkpoint))
     }
 }
@@ -0,0 +1,92 @@
+use rand::RngCore;
+use rand_distr::{Distribution, Exp1, StandardNormal, Uniform};
+use std::any::Any;
+
+use super::Space;
+
+/// Continuous space bounded by the given lower and upper bounds of each dimension.
+///
+/// Bounds can be infinite, where elements are sampled uniformly on bounded dimensions,
+/// from a shifted exponential on half-bounded dimensions and from a standard normal otherwise.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounded {
+    low: Vec<f64>,
+    high: Vec<f64>,
+}
+
+impl Bounded {
+    /// Constructs a bounded space given the lower and upper bounds of each dimension.
+    pub fn new(low: Vec<f64>, high: Vec<f64>) -> Self {
+        assert_eq!(low.len(), high.len(), "Bounds must have the same dimension");
+        assert!(
+            low.iter().zip(&high).all(|(l, h)| l <= h),
+            "Lower bounds must be less than or equal to upper bounds"
+        );
+
+        Self { low, high }
+    }
+
+    /// Gets the lower bounds.
+    pub fn low(&self) -> &[f64] {
+        &self.low
+    }
+
+    /// Gets the upper bounds.
+    pub fn high(&self) -> &[f64] {
+        &self.high
+    }
+
+    /// Clips the given element to the bounds of the space, e.g. an action outside the action space.
+    pub fn clip(&self, x: &[f64]) -> Vec<f64> {
+        assert_eq!(
+            x.len(),
+            self.low.len(),
+            "Element and bounds must have the same dimension"
+        );
+
+        x.iter()
+            .zip(self.low.iter().zip(&self.high))
+            .map(|(x, (&l, &h))| x.clamp(l, h))
+            .collect()
+    }
+}
+
+impl Space<Vec<f64>> for Bounded {
+    fn contains(&self, x: &Vec<f64>) -> bool {
+        x.len() == self.low.len()
+            && x.iter()
+                .zip(self.low.iter().zip(&self.high))
+                .all(|(x, (l, h))| l <= x && x <= h)
+    }
+
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        self.low
+            .iter()
+            .zip(&self.high)
+            .map(|(&l, &h)| match (l.is_finite(), h.is_finite()) {
+                (true, true) if l < h => Uniform::new_inclusive(l, h).sample(rng),
+                (true, true) => l,
+                (true, false) => l + Distribution::<f64>::sample(&Exp1, rng),
+                (false, true) => h - Distribution::<f64>::sample(&Exp1, rng),
+                (false, false) => Distribution::<f64>::sample(&StandardNormal, rng),
+            })
+            .collect()
+    }
+
+    fn len(&self) -> Option<usize> {
+        None
+    }
+
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = Vec<f64>> + 'a>> {
+        None
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn Space<Vec<f64>>) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+}
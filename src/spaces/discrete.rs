@@ -0,0 +1,37 @@
+use rand::{Rng, RngCore};
+use std::any::Any;
+
+use super::Space;
+
+/// Discrete space of `n` elements, i.e. the integers in `[0, n)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Discrete(pub usize);
+
+impl Space<usize> for Discrete {
+    fn contains(&self, x: &usize) -> bool {
+        *x < self.0
+    }
+
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> usize {
+        assert!(self.0 > 0, "Unable to sample from empty space");
+
+        rng.gen_range(0..self.0)
+    }
+
+    fn len(&self) -> Option<usize> {
+        Some(self.0)
+    }
+
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = usize> + 'a>> {
+        Some(Box::new(0..self.0))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn Space<usize>) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+}
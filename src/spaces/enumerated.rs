@@ -0,0 +1,76 @@
+use std::{any::Any, fmt::Debug};
+
+use rand::{Rng, RngCore};
+
+use super::Space;
+
+/// Finite space of explicitly enumerated elements, in insertion order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enumerated<T> {
+    elements: Vec<T>,
+}
+
+impl<T> Enumerated<T>
+where
+    T: PartialEq,
+{
+    /// Constructs a space given its elements, where duplicates are discarded.
+    pub fn new<I>(elements: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut space = Self { elements: vec![] };
+        for x in elements {
+            space.push(x);
+        }
+
+        space
+    }
+
+    /// Adds an element to the space, returning whether it was not already present.
+    pub fn push(&mut self, x: T) -> bool {
+        let is_new = !self.elements.contains(&x);
+        if is_new {
+            self.elements.push(x);
+        }
+
+        is_new
+    }
+
+    /// Gets the elements of the space.
+    pub fn elements(&self) -> &[T] {
+        &self.elements
+    }
+}
+
+impl<T> Space<T> for Enumerated<T>
+where
+    T: Clone + Debug + PartialEq + 'static,
+{
+    fn contains(&self, x: &T) -> bool {
+        self.elements.contains(x)
+    }
+
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T {
+        assert!(!self.elements.is_empty(), "Unable to sample from empty space");
+
+        self.elements[rng.gen_range(0..self.elements.len())].clone()
+    }
+
+    fn len(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = T> + 'a>> {
+        Some(Box::new(self.elements.iter().cloned()))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn Space<T>) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+}
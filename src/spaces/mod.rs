@@ -0,0 +1,16 @@
+mod space;
+pub use space::Space;
+
+mod discrete;
+pub use discrete::Discrete;
+
+mod bounded;
+pub use bounded::Bounded;
+
+mod multi_discrete;
+pub use multi_discrete::MultiDiscrete;
+
+mod enumerated;
+pub use enumerated::Enumerated;
+
+mod product;
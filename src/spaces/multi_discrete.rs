@@ -0,0 +1,49 @@
+use rand::{Rng, RngCore};
+use std::any::Any;
+
+use super::Space;
+
+/// Multi-dimensional discrete space, i.e. the cartesian product of discrete spaces of given sizes.
+///
+/// Elements are enumerated in lexicographic order, i.e. the last dimension varies fastest.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiDiscrete(pub Vec<usize>);
+
+impl Space<Vec<usize>> for MultiDiscrete {
+    fn contains(&self, x: &Vec<usize>) -> bool {
+        x.len() == self.0.len() && x.iter().zip(&self.0).all(|(x, n)| x < n)
+    }
+
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> Vec<usize> {
+        assert!(!self.is_empty(), "Unable to sample from empty space");
+
+        self.0.iter().map(|&n| rng.gen_range(0..n)).collect()
+    }
+
+    fn len(&self) -> Option<usize> {
+        self.0.iter().try_fold(1_usize, |len, &n| len.checked_mul(n))
+    }
+
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = Vec<usize>> + 'a>> {
+        let len = self.len()?;
+
+        // Decode the index of each element in mixed radix, with the last dimension varying fastest.
+        Some(Box::new((0..len).map(move |mut i| {
+            let mut x = vec![0; self.0.len()];
+            for (x, &n) in x.iter_mut().zip(&self.0).rev() {
+                *x = i % n;
+                i /= n;
+            }
+            x
+        })))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn Space<Vec<usize>>) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+}
@@ -0,0 +1,129 @@
+use std::{any::Any, fmt::Debug};
+
+use rand::RngCore;
+
+use super::Space;
+
+// The unit space, i.e. the product of no spaces, whose only element is the unit.
+impl Space<()> for () {
+    fn contains(&self, _x: &()) -> bool {
+        true
+    }
+
+    fn sample_dyn(&self, _rng: &mut dyn RngCore) {}
+
+    fn len(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = ()> + 'a>> {
+        Some(Box::new(std::iter::once(())))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn Space<()>) -> bool {
+        other.as_any().is::<Self>()
+    }
+}
+
+// The product of two spaces, enumerated in lexicographic order.
+impl<T0, T1, S0, S1> Space<(T0, T1)> for (S0, S1)
+where
+    T0: Clone + Debug + 'static,
+    T1: Debug + 'static,
+    S0: Space<T0> + 'static,
+    S1: Space<T1> + 'static,
+{
+    fn contains(&self, x: &(T0, T1)) -> bool {
+        self.0.contains(&x.0) && self.1.contains(&x.1)
+    }
+
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> (T0, T1) {
+        (self.0.sample_dyn(rng), self.1.sample_dyn(rng))
+    }
+
+    fn len(&self) -> Option<usize> {
+        self.0.len()?.checked_mul(self.1.len()?)
+    }
+
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = (T0, T1)> + 'a>> {
+        // Check the second space is enumerable, since it is iterated once for each element of the first.
+        let _ = self.1.iter()?;
+
+        Some(Box::new(self.0.iter()?.flat_map(move |x0| {
+            self.1
+                .iter()
+                .expect("Unable to iterate of enumerable space")
+                .map(move |x1| (x0.clone(), x1))
+        })))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    // Spaces are compared component-wise, since components are not required to be comparable.
+    fn eq_dyn(&self, other: &dyn Space<(T0, T1)>) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.0.eq_dyn(&other.0) && self.1.eq_dyn(&other.1))
+    }
+}
+
+// The product of three spaces, enumerated in lexicographic order.
+impl<T0, T1, T2, S0, S1, S2> Space<(T0, T1, T2)> for (S0, S1, S2)
+where
+    T0: Clone + Debug + 'static,
+    T1: Clone + Debug + 'static,
+    T2: Debug + 'static,
+    S0: Space<T0> + 'static,
+    S1: Space<T1> + 'static,
+    S2: Space<T2> + 'static,
+{
+    fn contains(&self, x: &(T0, T1, T2)) -> bool {
+        self.0.contains(&x.0) && self.1.contains(&x.1) && self.2.contains(&x.2)
+    }
+
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> (T0, T1, T2) {
+        (self.0.sample_dyn(rng), self.1.sample_dyn(rng), self.2.sample_dyn(rng))
+    }
+
+    fn len(&self) -> Option<usize> {
+        self.0.len()?.checked_mul(self.1.len()?)?.checked_mul(self.2.len()?)
+    }
+
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = (T0, T1, T2)> + 'a>> {
+        // Check the other spaces are enumerable, since they are iterated once for each element of the previous.
+        let _ = self.1.iter()?;
+        let _ = self.2.iter()?;
+
+        Some(Box::new(self.0.iter()?.flat_map(move |x0| {
+            self.1
+                .iter()
+                .expect("Unable to iterate of enumerable space")
+                .flat_map(move |x1| {
+                    let x0 = x0.clone();
+                    self.2
+                        .iter()
+                        .expect("Unable to iterate of enumerable space")
+                        .map(move |x2| (x0.clone(), x1.clone(), x2))
+                })
+        })))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    // Spaces are compared component-wise, since components are not required to be comparable.
+    fn eq_dyn(&self, other: &dyn Space<(T0, T1, T2)>) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.0.eq_dyn(&other.0) && self.1.eq_dyn(&other.1) && self.2.eq_dyn(&other.2))
+    }
+}
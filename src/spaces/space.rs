@@ -0,0 +1,45 @@
+use std::{any::Any, fmt::Debug};
+
+use rand::{Rng, RngCore};
+
+/// Definition of a space, i.e. the set of the actions or states of an environment.
+///
+/// A space can be finite, hence with a known number of elements, and enumerable, hence with
+/// an iterator over its elements, e.g. to maximize over the action space. Continuous or huge
+/// spaces are neither, but they can still be sampled and checked for membership.
+pub trait Space<T>: Debug {
+    /// Checks whether the given element belongs to the space.
+    fn contains(&self, x: &T) -> bool;
+
+    /// Samples an element of the space with given random number generator.
+    fn sample<R>(&self, rng: &mut R) -> T
+    where
+        R: Rng + ?Sized,
+        Self: Sized,
+    {
+        let mut rng = rng;
+
+        self.sample_dyn(&mut rng)
+    }
+
+    /// Samples an element of the space with given random number generator, as required by trait objects,
+    /// see [`Space::sample`].
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T;
+
+    /// Gets the number of elements of the space, if finite.
+    fn len(&self) -> Option<usize>;
+
+    /// Checks whether the space has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == Some(0)
+    }
+
+    /// Iterates of the elements of the space, if enumerable.
+    fn iter<'a>(&'a self) -> Option<Box<dyn Iterator<Item = T> + 'a>>;
+
+    /// Casts the space to [`Any`], e.g. to downcast it to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Checks whether the space is equal to the given one, i.e. of the same type with the same definition.
+    fn eq_dyn(&self, other: &dyn Space<T>) -> bool;
+}
@@ -2,16 +2,41 @@ use std::{fmt::Debug, hash::Hash};
 
 use num::cast::AsPrimitive;
 
-/// Definition of a generic action.
-pub trait Action: Clone + Debug + Eq + PartialEq + Hash {}
+/// Definition of a generic action, e.g. a continuous vector, which is not required to be hashable.
+pub trait Action: Clone + Debug + PartialEq + 'static {}
 
 /// Definition of a generic reward.
 pub trait Reward: AsPrimitive<f64> + Clone + Debug + PartialOrd {}
 
-/// Definition of a generic state.
-pub trait State: Clone + Debug + Eq + PartialEq + Hash {}
+/// Definition of a generic observation, e.g. a continuous vector, which is not required to be hashable.
+pub trait Observation: Clone + Debug {}
+
+/// Definition of a generic state, i.e. a hashable observation as required by tabular agents.
+pub trait State: Observation + Eq + PartialEq + Hash {}
 
 // FIXME: Restrict to primitive types.
-impl<T> Action for T where T: Clone + Debug + Eq + PartialEq + Hash {}
+impl<T> Action for T where T: Clone + Debug + PartialEq + 'static {}
 impl<T> Reward for T where T: AsPrimitive<f64> + Clone + Debug + PartialOrd {}
+impl<T> Observation for T where T: Clone + Debug {}
 impl<T> State for T where T: Clone + Debug + Eq + PartialEq + Hash {}
+
+/// Definition of a transition, i.e. the outcome of an action performed in a state.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transition<A, R, S>
+where
+    A: Action,
+    R: Reward,
+    S: Observation,
+{
+    /// State in which the action is performed.
+    pub state: S,
+    /// Performed action.
+    pub action: A,
+    /// Obtained reward.
+    pub reward: R,
+    /// Next state.
+    pub next_state: S,
+    /// End-of-episode flag.
+    pub is_done: bool,
+}
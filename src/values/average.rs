@@ -0,0 +1,56 @@
+use super::StateValue;
+use crate::types::Observation;
+
+/// State-independent value function, i.e. the average of the observed returns.
+///
+/// The average is the sample mean by default, or an exponential recency-weighted average
+/// given a constant step size, which tracks the returns of an improving policy.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Average {
+    alpha: Option<f64>,
+    value: f64,
+    count: usize,
+}
+
+impl Average {
+    /// Constructs a sample average.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the constant step size of the average.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step size is not in `(0, 1]`.
+    pub fn with_step_size(mut self, alpha: f64) -> Self {
+        assert!(alpha > 0. && alpha <= 1., "Step size must be in (0, 1]");
+        self.alpha = Some(alpha);
+
+        self
+    }
+}
+
+impl<S> StateValue<f64, S> for Average
+where
+    S: Observation,
+{
+    fn call(&self, _state: &S) -> f64 {
+        self.value
+    }
+
+    fn reset(&mut self) -> &mut Self {
+        self.value = 0.;
+        self.count = 0;
+
+        self
+    }
+
+    fn update(&mut self, _state: &S, target: &f64) {
+        self.count += 1;
+        // Move the average towards the target.
+        let alpha = self.alpha.unwrap_or(1. / self.count as f64);
+        self.value += alpha * (target - self.value);
+    }
+}
@@ -0,0 +1,114 @@
+use super::StateActionValue;
+use crate::{
+    features::Features,
+    spaces::{Enumerated, Space},
+    types::Action,
+};
+
+/// Linear state-action value function over the features of the states, one weight vector for each action.
+///
+/// The function is updated with semi-gradient steps towards the given target, as in Sutton & Barto
+/// (Section 10.1), where the target is e.g. the bootstrapped return computed by the agent.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Linear<A, F>
+where
+    A: Action,
+    F: Features<Vec<f64>>,
+{
+    actions: Enumerated<A>,
+    features: F,
+    weights: Vec<Vec<f64>>,
+    alpha: f64,
+}
+
+impl<A, F> Linear<A, F>
+where
+    A: Action,
+    F: Features<Vec<f64>>,
+{
+    /// Constructs a linear function given the action space and the features of the states,
+    /// with zero weights.
+    pub fn new<I>(actions: I, features: F) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let actions = Enumerated::new(actions);
+        let weights = vec![vec![0.; features.len()]; actions.elements().len()];
+
+        Self {
+            actions,
+            features,
+            weights,
+            alpha: 0.1,
+        }
+    }
+
+    /// Sets the step size of the updates.
+    pub fn with_step_size(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+
+        self
+    }
+
+    /// Gets the features of the states.
+    pub fn features(&self) -> &F {
+        &self.features
+    }
+
+    /// Gets the weights associated with the given action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the action is not in the action space.
+    pub fn weights(&self, action: &A) -> &[f64] {
+        &self.weights[self.index(action)]
+    }
+
+    /// Gets the index of the weights of the given action.
+    fn index(&self, action: &A) -> usize {
+        self.actions
+            .elements()
+            .iter()
+            .position(|a| a == action)
+            .unwrap_or_else(|| panic!("Unknown action {:?}", action))
+    }
+}
+
+impl<A, F> StateActionValue<A, f64, Vec<f64>> for Linear<A, F>
+where
+    A: Action,
+    F: Features<Vec<f64>>,
+{
+    fn action_space(&self) -> &dyn Space<A> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        self.features.state_space()
+    }
+
+    fn call(&self, action: &A, state: &Vec<f64>) -> f64 {
+        let w = &self.weights[self.index(action)];
+
+        self.features
+            .call_sparse(state)
+            .into_iter()
+            .map(|(i, x)| w[i] * x)
+            .sum()
+    }
+
+    fn reset(&mut self) -> &mut Self {
+        self.weights.iter_mut().flatten().for_each(|w| *w = 0.);
+
+        self
+    }
+
+    fn update(&mut self, action: &A, reward: &f64, state: &Vec<f64>) {
+        let (j, x) = (self.index(action), self.features.call_sparse(state));
+        let w = &mut self.weights[j];
+        // Compute the error of the current estimate, then step along its gradient, i.e. the features.
+        let delta = reward - x.iter().map(|&(i, x)| w[i] * x).sum::<f64>();
+        x.into_iter().for_each(|(i, x)| w[i] += self.alpha * delta * x);
+    }
+}
@@ -1,2 +1,11 @@
 mod value;
-pub use value::{ActionValue, StateActionValue};
+pub use value::{ActionValue, StateActionValue, StateValue};
+
+mod average;
+pub use average::Average;
+
+mod linear;
+pub use linear::Linear;
+
+mod neural;
+pub use neural::Neural;
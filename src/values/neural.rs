@@ -0,0 +1,183 @@
+use super::StateActionValue;
+use crate::{
+    networks::{Loss, Mlp, Optimizer},
+    spaces::{Bounded, Enumerated, Space},
+    types::Action,
+};
+
+/// Neural state-action value function, i.e. a network mapping each state to the values of all the actions.
+///
+/// If dueling, as in Wang et al. (2016), the network outputs the value of the state followed by the
+/// advantage of each action, which are aggregated into the values of the actions by subtracting
+/// the mean advantage. The network is trained by gradient descent on the loss of a batch of targets.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Neural<A, O>
+where
+    A: Action,
+    O: Optimizer,
+{
+    actions: Enumerated<A>,
+    states: Bounded,
+    network: Mlp,
+    optimizer: O,
+    loss: Loss,
+    is_dueling: bool,
+}
+
+impl<A, O> Neural<A, O>
+where
+    A: Action,
+    O: Optimizer,
+{
+    /// Constructs a neural function given the action space, the state space,
+    /// the network, whose outputs are the values of the actions, and its optimizer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inputs of the network do not match the state space
+    /// or if its outputs do not match the action space.
+    pub fn new<I>(actions: I, states: Bounded, network: Mlp, optimizer: O) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Self::with_head(actions, states, network, optimizer, false)
+    }
+
+    /// Constructs a dueling neural function given the action space, the state space,
+    /// the network, whose outputs are the value of the state followed by the advantages
+    /// of the actions, and its optimizer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inputs of the network do not match the state space
+    /// or if its outputs do not match the action space plus one.
+    pub fn dueling<I>(actions: I, states: Bounded, network: Mlp, optimizer: O) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Self::with_head(actions, states, network, optimizer, true)
+    }
+
+    /// Sets the loss of the targets.
+    pub fn with_loss(mut self, loss: Loss) -> Self {
+        self.loss = loss;
+
+        self
+    }
+
+    /// Gets the network of the function.
+    pub fn network(&self) -> &Mlp {
+        &self.network
+    }
+
+    /// Computes the values of all the actions given the state, in the order of the action space.
+    pub fn values(&self, state: &[f64]) -> Vec<f64> {
+        let y = self.network.call(state);
+        if !self.is_dueling {
+            return y;
+        }
+        // Aggregate the value of the state and the advantages of the actions.
+        let (v, a) = (y[0], &y[1..]);
+        let mean = a.iter().sum::<f64>() / a.len() as f64;
+
+        a.iter().map(|a| v + a - mean).collect()
+    }
+
+    /// Updates the function given a batch of performed actions, their targets and the states
+    /// they were performed in, with a single step of the optimizer on the mean loss.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any action is not in the action space.
+    pub fn update_batch(&mut self, batch: &[(A, f64, Vec<f64>)]) {
+        if batch.is_empty() {
+            return;
+        }
+
+        let n = self.actions.elements().len();
+        let mut grad = vec![0.; self.network.parameters().len()];
+        for (action, target, state) in batch {
+            let i = self.index(action);
+            // Only the output of the performed action contributes to the loss ...
+            let g = self.loss.derivative(self.values(state)[i], *target) / batch.len() as f64;
+            // ... which is propagated through the aggregation of the dueling head, if any.
+            let dy = match self.is_dueling {
+                false => (0..n).map(|j| g * (i == j) as u8 as f64).collect(),
+                true => std::iter::once(g)
+                    .chain((0..n).map(|j| g * ((i == j) as u8 as f64 - 1. / n as f64)))
+                    .collect::<Vec<_>>(),
+            };
+            for (x, y) in grad.iter_mut().zip(self.network.gradient(state, &dy)) {
+                *x += y;
+            }
+        }
+        let step = self.optimizer.step(&grad);
+        self.network.update(&step);
+    }
+
+    /// Constructs the function given whether the head of the network is dueling.
+    fn with_head<I>(actions: I, states: Bounded, network: Mlp, optimizer: O, is_dueling: bool) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let actions = Enumerated::new(actions);
+        assert_eq!(
+            network.inputs(),
+            states.low().len(),
+            "Network inputs and state space must have the same dimension"
+        );
+        assert_eq!(
+            network.outputs(),
+            actions.elements().len() + is_dueling as usize,
+            "Network outputs must match the action space"
+        );
+
+        Self {
+            actions,
+            states,
+            network,
+            optimizer,
+            loss: Default::default(),
+            is_dueling,
+        }
+    }
+
+    /// Gets the index of the output of the given action.
+    fn index(&self, action: &A) -> usize {
+        self.actions
+            .elements()
+            .iter()
+            .position(|a| a == action)
+            .unwrap_or_else(|| panic!("Unknown action {:?}", action))
+    }
+}
+
+impl<A, O> StateActionValue<A, f64, Vec<f64>> for Neural<A, O>
+where
+    A: Action,
+    O: Optimizer,
+{
+    fn action_space(&self) -> &dyn Space<A> {
+        &self.actions
+    }
+
+    fn state_space(&self) -> &dyn Space<Vec<f64>> {
+        &self.states
+    }
+
+    fn call(&self, action: &A, state: &Vec<f64>) -> f64 {
+        self.values(state)[self.index(action)]
+    }
+
+    fn reset(&mut self) -> &mut Self {
+        self.network.reset();
+        self.optimizer.reset();
+
+        self
+    }
+
+    fn update(&mut self, action: &A, reward: &f64, state: &Vec<f64>) {
+        self.update_batch(&[(action.clone(), *reward, state.clone())]);
+    }
+}
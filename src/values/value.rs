@@ -1,4 +1,8 @@
-use crate::types::{Action, Reward, State};
+use crate::{
+    spaces::Space,
+    types::{Action, Observation, Reward},
+    Result,
+};
 
 /// Definition of the action value function.
 pub trait ActionValue<A, R>
@@ -6,17 +10,29 @@ where
     A: Action,
     R: Reward,
 {
-    /// Iterates of the action space.
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a A> + 'a>;
+    /// Gets the action space.
+    fn action_space(&self) -> &dyn Space<A>;
 
     /// Computes the expected reward of the given action.
-    fn call(&self, action: &A) -> R;
+    fn call(&self, action: &A) -> R {
+        self.try_call(action).expect("Unable to compute the action value")
+    }
+
+    /// Computes the expected reward of the given action, failing if the action is not in the action space.
+    fn try_call(&self, action: &A) -> Result<R>;
 
     /// Resets the function.
     fn reset(&mut self) -> &mut Self;
 
     /// Updates the agent given performed action and obtained reward.
-    fn update(&mut self, action: &A, reward: &R);
+    fn update(&mut self, action: &A, reward: &R) {
+        self.try_update(action, reward)
+            .expect("Unable to update the action value function")
+    }
+
+    /// Updates the agent given performed action and obtained reward,
+    /// failing if the action is not in the action space.
+    fn try_update(&mut self, action: &A, reward: &R) -> Result<()>;
 }
 
 /// Definition of the state-action value function.
@@ -24,13 +40,13 @@ pub trait StateActionValue<A, R, S>
 where
     A: Action,
     R: Reward,
-    S: State,
+    S: Observation,
 {
-    /// Iterates of the action space.
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a A> + 'a>;
+    /// Gets the action space.
+    fn action_space(&self) -> &dyn Space<A>;
 
-    /// Iterates of the state space.
-    fn states_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a S> + 'a>;
+    /// Gets the state space.
+    fn state_space(&self) -> &dyn Space<S>;
 
     /// Computes the expected reward of the given action-state pair.
     fn call(&self, action: &A, state: &S) -> R;
@@ -38,10 +54,27 @@ where
     /// Resets the function.
     fn reset(&mut self) -> &mut Self;
 
-    /// Updates the agent given performed action, obtained reward and next state.
+    /// Updates the function given performed action, obtained reward and the state it was performed in,
+    /// where the reward can be a target computed by the agent, e.g. a bootstrapped return.
     fn update(&mut self, action: &A, reward: &R, state: &S);
 }
 
+/// Definition of the state value function, e.g. a baseline of policy-based agents.
+pub trait StateValue<R, S>
+where
+    R: Reward,
+    S: Observation,
+{
+    /// Computes the expected return of the given state.
+    fn call(&self, state: &S) -> R;
+
+    /// Resets the function.
+    fn reset(&mut self) -> &mut Self;
+
+    /// Updates the function given a state and its observed return.
+    fn update(&mut self, state: &S, target: &R);
+}
+
 // Auto-implements state-action value function for action value function
 // using nil state as agent's state, i.e. S = ().
 impl<A, R, V> StateActionValue<A, R, ()> for V
@@ -50,13 +83,13 @@ where
     R: Reward,
     V: ActionValue<A, R>,
 {
-    fn actions_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a A> + 'a> {
-        self.actions_iter()
+    fn action_space(&self) -> &dyn Space<A> {
+        self.action_space()
     }
 
-    fn states_iter<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = &'a ()> + 'a> {
+    fn state_space(&self) -> &dyn Space<()> {
         // The nil state is the only state.
-        Box::new([()].iter())
+        &()
     }
 
     fn call(&self, action: &A, _state: &()) -> R {
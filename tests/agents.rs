@@ -0,0 +1,229 @@
+mod agents {
+    use approx::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::{
+        agents::{
+            bandits::{
+                arms::{Arm, Bernoulli},
+                Arms, MultiArmedBandit,
+            },
+            Agent, Dqn, QLearning, Reinforce, Sarsa,
+        },
+        envs::{CartPole, Continuous, MountainCar, Observable},
+        features::TileCoding,
+        networks::{Activation, Adam, Mlp},
+        policies::{EpsilonGreedy, Gaussian, Greedy},
+        sessions::{NoProgress, Repetition, Session, TrainTestSession},
+        spaces::Bounded,
+        types::Transition,
+        values::{ActionValue, Average, Linear, Neural},
+        Error,
+    };
+
+    #[test]
+    pub fn bernoulli() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Parameters must be positive.
+        assert!(Bernoulli::try_new(2., 1.).is_ok());
+        for (alpha, beta) in [(0., 1.), (1., -1.), (f64::NAN, 1.), (1., f64::INFINITY)] {
+            assert!(matches!(
+                Bernoulli::try_new(alpha, beta),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+        // Sampling fails if the parameters are invalidated by the updates.
+        let mut arm = Bernoulli::default();
+        assert!(arm.try_sample(&mut rng).is_ok());
+        arm.update(&3.);
+        assert!(matches!(arm.try_sample(&mut rng), Err(Error::InvalidParameter(_))));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn bernoulli_should_panic() {
+        Bernoulli::new(0., 1.);
+    }
+
+    #[test]
+    pub fn arms() {
+        let mut arms = Arms::<usize, f64, Bernoulli>::new(0..2);
+        // Updating a known action succeeds.
+        assert!(arms.try_update(&1, &1.).is_ok());
+        assert_eq!(arms.call(&1), 2. / 3.);
+        // Updating an unknown action fails.
+        assert!(matches!(arms.try_update(&2, &1.), Err(Error::UnknownAction(a)) if a == "2"));
+        assert!(matches!(arms.try_call(&2), Err(Error::UnknownAction(a)) if a == "2"));
+    }
+
+    #[test]
+    pub fn contextual_bandit() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        // Each state falls into its own tile, hence its own context.
+        let states = Bounded::new(vec![0.], vec![1.]);
+        let v = Linear::new(0..2, TileCoding::new(states, vec![2], 1, 64)).with_step_size(1.);
+        let mut mab = MultiArmedBandit::new(Greedy::default(), v);
+        // The reward is credited to the state the action is performed in, not to the next state.
+        for (state, action) in [(0., 1), (1., 0)] {
+            let transition = Transition {
+                state: vec![state],
+                action,
+                reward: 1.,
+                next_state: vec![1. - state],
+                is_done: false,
+            };
+            mab.update(&transition, &mut rng);
+        }
+        mab.set_eval(true);
+        assert_eq!(mab.call(&vec![0.], &mut rng), 1);
+        assert_eq!(mab.call(&vec![1.], &mut rng), 0);
+    }
+
+    #[test]
+    pub fn reinforce() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Initialize a Gaussian agent on a one-step task, whose optimal action is one.
+        let pi = Gaussian::new(Bounded::new(vec![-2.], vec![2.]), Bounded::new(vec![0.], vec![1.])).with_std(0.5);
+        let mut agent = Reinforce::new(pi, Average::new().with_step_size(0.1)).with_step_size(0.05);
+        let state = vec![1.];
+        for _ in 0..2_000 {
+            let action = agent.call(&state, &mut rng);
+            let reward = -(action[0] - 1.).powi(2);
+            agent.update(
+                &Transition {
+                    state: state.clone(),
+                    action,
+                    reward,
+                    next_state: state.clone(),
+                    is_done: true,
+                },
+                &mut rng,
+            );
+        }
+        // The mean of the policy approaches the optimal action.
+        agent.set_eval(true);
+        assert_relative_eq!(agent.call(&state, &mut rng)[0], 1., epsilon = 0.2);
+        // Resetting the agent resets the policy.
+        agent.reset();
+        assert_eq!(agent.call(&state, &mut rng), vec![0.]);
+    }
+
+    // Trains a linear agent with tile coding on the mountain car, returning the mean length of the test episodes.
+    fn mountain_car<G>(agent: &mut G) -> f64
+    where
+        G: Agent<usize, f64, Vec<f64>, EpsilonGreedy, Linear<usize, TileCoding>> + Clone + Send,
+    {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Initialize the env with continuous states.
+        let mut env = Continuous::new(MountainCar::default().with_horizon(1_000));
+        // Greedy test episodes are penalized by the number of steps to reach the goal.
+        let data = TrainTestSession::new(100, 5, 2)
+            .with_progress(NoProgress)
+            .call(agent, &mut env, &mut rng);
+        let reward: Vec<f64> = data
+            .column("reward")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+
+        -reward.iter().sum::<f64>() / reward.len() as f64
+    }
+
+    // Initializes a linear value function with tile coding over the mountain car states.
+    fn tile_coding() -> Linear<usize, TileCoding> {
+        let states = Bounded::new(
+            vec![MountainCar::POSITION.0, MountainCar::VELOCITY.0],
+            vec![MountainCar::POSITION.1, MountainCar::VELOCITY.1],
+        );
+        // Split the step size among the tilings.
+        Linear::new(0..3, TileCoding::new(states, vec![8, 8], 8, 4096)).with_step_size(0.5 / 8.)
+    }
+
+    #[test]
+    pub fn sarsa() {
+        // Optimistic initial values drive the exploration without randomness.
+        let mut agent = Sarsa::new(EpsilonGreedy::new(0.), tile_coding());
+        // The agent learns to reach the goal, which takes more than a thousand steps at random.
+        assert!(mountain_car(&mut agent) < 400.);
+    }
+
+    #[test]
+    pub fn q_learning() {
+        let mut agent = QLearning::new(EpsilonGreedy::new(0.), tile_coding());
+        assert!(mountain_car(&mut agent) < 400.);
+    }
+
+    // Trains a neural agent on the cart-pole, returning the mean length of the test episodes.
+    fn cart_pole<G>(agent: &mut G) -> f64
+    where
+        G: Agent<usize, f64, Vec<f64>, EpsilonGreedy, Neural<usize, Adam>> + Clone + Send,
+    {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Initialize the env with continuous states.
+        let mut env = Continuous::new(CartPole::default());
+        // Greedy test episodes are rewarded by the number of steps the pole is balanced.
+        let data = TrainTestSession::new(100, 5, 1)
+            .with_repetition(Repetition::Independent)
+            .with_progress(NoProgress)
+            .call(agent, &mut env, &mut rng);
+        let reward: Vec<f64> = data
+            .column("reward")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+
+        reward.iter().sum::<f64>() / reward.len() as f64
+    }
+
+    // Initializes a deep Q-network agent over the cart-pole states, with a dueling head if required.
+    fn neural(is_dueling: bool) -> Dqn<usize, EpsilonGreedy, Adam> {
+        // Initialize the random number generator of the weights.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let states = CartPole::default().observation_space().clone();
+        let network = Mlp::new(vec![4, 32, 32, 2 + is_dueling as usize], Activation::ReLU, &mut rng);
+        let v = match is_dueling {
+            false => Neural::new(0..2, states, network, Adam::new(2e-3)),
+            true => Neural::dueling(0..2, states, network, Adam::new(2e-3)),
+        };
+        // Explore at random first, then decay the exploration.
+        Dqn::new(EpsilonGreedy::new(1.).with_decay(0.05, 1_000), v)
+            .with_batch_size(16)
+            .with_target_period(100)
+    }
+
+    #[test]
+    pub fn dqn() {
+        let mut agent = neural(false);
+        // The agent learns to balance the pole, which falls in about twenty steps at random.
+        assert!(cart_pole(&mut agent) > 50.);
+        // The agent is deterministic given the random number generator of the session.
+        let mut env = Continuous::new(CartPole::default());
+        let session = TrainTestSession::new(10, 1, 2)
+            .with_repetition(Repetition::Independent)
+            .with_progress(NoProgress);
+        let data = session.call(&mut agent, &mut env, &mut Xoshiro256PlusPlus::seed_from_u64(42));
+        assert!(data.frame_equal(&session.call(&mut agent, &mut env, &mut Xoshiro256PlusPlus::seed_from_u64(42))));
+    }
+
+    #[test]
+    pub fn double_dqn() {
+        let mut agent = neural(false).with_double(true);
+        assert!(cart_pole(&mut agent) > 50.);
+    }
+
+    #[test]
+    pub fn dueling_dqn() {
+        let mut agent = neural(true);
+        assert!(cart_pole(&mut agent) > 50.);
+    }
+}
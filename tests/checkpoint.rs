@@ -0,0 +1,122 @@
+#![cfg(feature = "serde")]
+
+mod checkpoint {
+    use std::{
+        fs::remove_dir_all,
+        ops::ControlFlow,
+        panic::{catch_unwind, AssertUnwindSafe},
+    };
+
+    use rand::SeedableRng;
+    use rand_distr::Normal;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::{
+        agents::{
+            bandits::{arms::Bernoulli, Arms, MultiArmedBandit},
+            Agent,
+        },
+        envs::{Env, FarWest},
+        policies::EpsilonGreedy,
+        sessions::{Callback, Checkpoint, NoProgress, OnlineSession, Session, TrainTestSession},
+    };
+
+    type Mab = MultiArmedBandit<usize, f64, (), EpsilonGreedy, Arms<usize, f64, Bernoulli>>;
+
+    // Initializes a far-west environment with oracle and an epsilon-greedy MAB.
+    fn far_west() -> (Mab, FarWest<Normal<f64>>) {
+        let env = [Normal::new(0., 1.), Normal::new(1., 1.), Normal::new(2., 1.)]
+            .into_iter()
+            .map(|d| d.unwrap());
+        let env = FarWest::new(env, 9).with_oracle();
+        let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+        let mab = MultiArmedBandit::new(EpsilonGreedy::new(0.10), Arms::from_actions_arms_iter(mab));
+
+        (mab, env)
+    }
+
+    // Interrupts the session at the given repetition and episode.
+    struct Interrupt(usize, usize);
+
+    impl Callback<usize, f64, (), Mab, FarWest<Normal<f64>>> for Interrupt {
+        fn on_episode_end(
+            &self,
+            repeat: usize,
+            episode: usize,
+            _agent: &Mab,
+            _environment: &FarWest<Normal<f64>>,
+        ) -> ControlFlow<()> {
+            assert!((repeat, episode) != (self.0, self.1), "Interrupted");
+
+            ControlFlow::Continue(())
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Train the MAB.
+        let (mut mab, mut env) = far_west();
+        OnlineSession::new(5, 1)
+            .with_progress(NoProgress)
+            .call(&mut mab, &mut env, &mut rng);
+        // Serialize and deserialize the agent and the environment.
+        let json = serde_json::to_value(&mab).unwrap();
+        let other: Mab = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&other).unwrap(), json);
+        let json = serde_json::to_string(&env).unwrap();
+        let restored: FarWest<Normal<f64>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(format!("{:?}", restored), format!("{:?}", env));
+        // The restored agent behaves as the original one.
+        let mut rng_a = Xoshiro256PlusPlus::seed_from_u64(0);
+        let mut rng_b = rng_a.clone();
+        for _ in 0..10 {
+            assert_eq!(mab.call(&(), &mut rng_a), other.call(&(), &mut rng_b));
+        }
+    }
+
+    #[test]
+    fn resume() {
+        let path = std::env::temp_dir().join(format!("reilly-checkpoint-{}", std::process::id()));
+        let (mab, env) = far_west();
+        // Execute the sessions without interruption.
+        let online = OnlineSession::new(6, 2).with_progress(NoProgress);
+        let train_test = TrainTestSession::new(6, 3, 2).with_progress(NoProgress);
+        let rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let expected = [
+            online.call(&mut mab.clone(), &mut env.clone(), &mut rng.clone()),
+            train_test.call(&mut mab.clone(), &mut env.clone(), &mut rng.clone()),
+        ];
+        for (k, expected) in expected.into_iter().enumerate() {
+            let checkpoint = Checkpoint::new(path.join(k.to_string()), 2);
+            let call = |callbacks: &[&dyn Callback<_, _, _, _, _>]| match k {
+                0 => online
+                    .call_with_checkpoint(
+                        &mut mab.clone(),
+                        &mut env.clone(),
+                        &mut rng.clone(),
+                        callbacks,
+                        &checkpoint,
+                    )
+                    .unwrap(),
+                _ => train_test
+                    .call_with_checkpoint(
+                        &mut mab.clone(),
+                        &mut env.clone(),
+                        &mut rng.clone(),
+                        callbacks,
+                        &checkpoint,
+                    )
+                    .unwrap(),
+            };
+            // Interrupt the second repetition at the end of its fourth episode.
+            assert!(catch_unwind(AssertUnwindSafe(|| call(&[&Interrupt(1, 3)]))).is_err());
+            assert!(checkpoint.snapshot_path(1).exists());
+            // Resume the session from the last snapshot.
+            assert!(call(&[]).frame_equal(&expected));
+            // Completed repetitions are not executed again.
+            assert!(call(&[&Interrupt(1, 3)]).frame_equal(&expected));
+        }
+        remove_dir_all(&path).unwrap();
+    }
+}
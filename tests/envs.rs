@@ -1,24 +1,58 @@
 mod envs {
-    use std::fs::File;
+    use std::collections::HashMap;
 
-    use polars::prelude::*;
+    use approx::*;
     use rand::SeedableRng;
-    use rand_distr::Normal;
+    use rand_distr::{Normal, Uniform};
     use rand_xoshiro::Xoshiro256PlusPlus;
     use reilly::{
         agents::{
             bandits::{arms::Bernoulli, Arms, MultiArmedBandit},
             Agent,
         },
-        envs::{Env, FarWest},
+        envs::{
+            Acrobot, Blackjack, BlackjackAction, CartPole, Continuous, Discretizer, Drift, Env, FarWest, FiniteMdp,
+            GamblersProblem, GridAction, GridWorld, JacksCarRental, MountainCar, NonStationaryBandit, Observable,
+            Pendulum, RandomWalk, Taxi, TaxiAction,
+        },
         policies::EpsilonGreedy,
+        results::{export, Format, Metadata},
         sessions::{Session, TrainTestSession},
+        spaces::Space,
     };
 
+    // Computes the optimal state value function of a finite MDP with value iteration.
+    fn value_iteration<A, S, E>(env: &E, gamma: f64, theta: f64) -> HashMap<S, f64>
+    where
+        A: reilly::types::Action,
+        S: reilly::types::State,
+        E: FiniteMdp<A, f64, S>,
+    {
+        let mut v: HashMap<S, f64> = env.state_space().iter().unwrap().map(|s| (s, 0.)).collect();
+        loop {
+            let mut delta: f64 = 0.;
+            for s in env.state_space().iter().unwrap() {
+                let q = env.action_space().iter().unwrap().map(|a| {
+                    env.transitions(&s, &a)
+                        .into_iter()
+                        .map(|(p, r, s_, is_done)| p * (r + if is_done { 0. } else { gamma * v[&s_] }))
+                        .sum::<f64>()
+                });
+                let q = q.fold(f64::NEG_INFINITY, f64::max);
+                delta = delta.max((v[&s] - q).abs());
+                v.insert(s, q);
+            }
+            if delta < theta {
+                return v;
+            }
+        }
+    }
+
     #[test]
     fn far_west() {
         // Initialize the random number generator.
-        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        let seed = rand::random();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
         // Initialize the env.
         let env = [
             Normal::new(0., 1.),
@@ -31,7 +65,7 @@ mod envs {
         .map(|d| d.unwrap());
         let mut env = FarWest::new(env, 1_000);
         // Initialize the MAB.
-        let mab = env.actions_iter().map(|&a| (a, Bernoulli::default()));
+        let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
         let mut mab = MultiArmedBandit::new(
             // Initialize an epsilon-greedy policy.
             EpsilonGreedy::new(0.10),
@@ -41,8 +75,462 @@ mod envs {
         // Execute the experiment session.
         let session = TrainTestSession::new(10, 3, 500);
         let mut data = session.call(&mut mab, &mut env, &mut rng);
-        // Write data to CSV.
-        let mut file = File::create("tests/out.csv").unwrap();
-        CsvWriter::new(&mut file).has_header(true).finish(&mut data).unwrap();
+        // Write data to CSV in the temporary directory, together with the run metadata.
+        let metadata = Metadata::new().with_seed(seed).with_agent(&mab).with_environment(&env);
+        let path = std::env::temp_dir().join("reilly_far_west.csv");
+        export(&mut data, path, Format::Csv, &metadata).unwrap();
+    }
+
+    #[test]
+    fn far_west_oracle() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let env = [Normal::new(0., 1.), Normal::new(9., 4.), Normal::new(7., 3.)]
+            .into_iter()
+            .map(|d| d.unwrap());
+        let mut env = FarWest::new(env, 100);
+        // The oracle is disabled by default.
+        assert!(env.oracle().is_none());
+        env = env.with_oracle();
+        // The oracle knows the expected rewards.
+        let oracle = env.oracle().unwrap();
+        assert_eq!(oracle.optimal_action(&()), 1);
+        assert_eq!(oracle.optimal_reward(&()), 9.);
+        assert_eq!(oracle.regret(&0, &()), 9.);
+        assert_eq!(oracle.regret(&1, &()), 0.);
+        // The means of other distributions can be given explicitly.
+        let uniform = [Uniform::new(0., 2.), Uniform::new(1., 5.)].into_iter();
+        let uniform = FarWest::new(uniform, 100).with_means(vec![1., 3.]);
+        assert_eq!(uniform.oracle().unwrap().optimal_action(&()), 1);
+        // Sessions record the pseudo-regret and the percent of optimal actions.
+        let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+        let mut mab = MultiArmedBandit::new(EpsilonGreedy::new(0.10), Arms::from_actions_arms_iter(mab));
+        let data = TrainTestSession::new(1, 2, 3).call(&mut mab, &mut env, &mut rng);
+        assert_eq!(
+            data.get_column_names(),
+            ["reps", "test", "mode", "reward", "regret", "optimal"]
+        );
+        let optimal = data.column("optimal").unwrap().f64().unwrap();
+        assert!(optimal.into_iter().all(|p| (0. ..=1.).contains(&p.unwrap())));
+    }
+
+    #[test]
+    fn grid_world() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = GridWorld::new(
+            "
+            S.#
+            ..G
+            ",
+        );
+        assert_eq!(env.state_space().len().unwrap(), 5);
+        assert_eq!(env.action_space().len().unwrap(), 4);
+        // Walls and boundaries cannot be crossed.
+        assert_eq!(
+            env.reset(&mut rng).call_mut(&GridAction::Up, &mut rng),
+            (0., (0, 0), false)
+        );
+        assert_eq!(env.call_mut(&GridAction::Right, &mut rng), (0., (0, 1), false));
+        assert_eq!(env.call_mut(&GridAction::Right, &mut rng), (0., (0, 1), false));
+        // Reaching the goal terminates the episode.
+        assert_eq!(env.call_mut(&GridAction::Down, &mut rng), (0., (1, 1), false));
+        assert_eq!(env.call_mut(&GridAction::Right, &mut rng), (1., (1, 2), true));
+        // Reset the environment to the start cell.
+        assert_eq!(env.reset(&mut rng).get_state(), (0, 0));
+        // Cell rewards override the rewards of their types.
+        let mut env = env.with_cell_reward((1, 0), -5.).with_cell_reward((1, 2), 10.);
+        assert_eq!(env.call_mut(&GridAction::Down, &mut rng), (-5., (1, 0), false));
+        assert_eq!(env.call_mut(&GridAction::Right, &mut rng), (0., (1, 1), false));
+        assert_eq!(env.call_mut(&GridAction::Right, &mut rng), (10., (1, 2), true));
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_world_should_panic() {
+        GridWorld::new("S#").with_cell_reward((0, 1), 1.);
+    }
+
+    #[test]
+    fn cliff_walking() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = GridWorld::cliff_walking();
+        // Falling off the cliff sends the agent back to start.
+        assert_eq!(
+            env.reset(&mut rng).call_mut(&GridAction::Right, &mut rng),
+            (-100., (3, 0), false)
+        );
+        // Follow the optimal path.
+        let path = std::iter::once(GridAction::Up)
+            .chain(std::iter::repeat_n(GridAction::Right, 11))
+            .chain(std::iter::once(GridAction::Down));
+        let (mut cum_reward, mut is_done) = (0., false);
+        for action in path {
+            assert!(!is_done);
+            let (reward, _, done) = env.call_mut(&action, &mut rng);
+            cum_reward += reward;
+            is_done = done;
+        }
+        assert!(is_done);
+        assert_eq!(cum_reward, -13.);
+    }
+
+    #[test]
+    fn windy_grid_world() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = GridWorld::windy_grid_world(false, false);
+        // Move into the windy columns.
+        env.reset(&mut rng);
+        for _ in 0..3 {
+            env.call_mut(&GridAction::Right, &mut rng);
+        }
+        assert_eq!(env.get_state(), (3, 3));
+        assert_eq!(env.call_mut(&GridAction::Right, &mut rng), (-1., (2, 4), false));
+        // King's moves extend the action space.
+        let env = GridWorld::windy_grid_world(true, true);
+        assert_eq!(env.action_space().len().unwrap(), 8);
+    }
+
+    #[test]
+    fn frozen_lake() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let env = GridWorld::frozen_lake_8x8();
+        assert_eq!(env.state_space().len().unwrap(), 64);
+        // Without slipping, falling into a pit terminates the episode.
+        let mut env = GridWorld::frozen_lake_4x4().with_slip(0.);
+        env.reset(&mut rng).call_mut(&GridAction::Right, &mut rng);
+        assert_eq!(env.call_mut(&GridAction::Down, &mut rng), (0., (1, 1), true));
+        // Slipping never moves the agent backwards.
+        let mut env = GridWorld::frozen_lake_4x4().with_horizon(1);
+        for _ in 0..100 {
+            let (_, state, is_done) = env.reset(&mut rng).call_mut(&GridAction::Down, &mut rng);
+            assert!([(0, 0), (1, 0), (0, 1)].contains(&state));
+            assert!(is_done);
+        }
+    }
+
+    #[test]
+    fn taxi() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = Taxi::default();
+        assert_eq!(env.state_space().len().unwrap(), 500);
+        assert_eq!(env.action_space().len().unwrap(), 6);
+        for _ in 0..100 {
+            // The passenger is initially waiting at a location different from its destination.
+            let (_, _, p, d) = env.reset(&mut rng).get_state();
+            assert!(p < 4 && p != d);
+            // Dropping off an absent passenger is penalized.
+            assert_eq!(env.call_mut(&TaxiAction::Dropoff, &mut rng).0, -10.);
+        }
+        // Random episodes are truncated by the time horizon.
+        let actions: Vec<_> = env.action_space().iter().unwrap().collect();
+        let mut env = Taxi::new(10);
+        let mut count = 0;
+        env.reset(&mut rng);
+        while !env.call_mut(&actions[count % actions.len()], &mut rng).2 {
+            count += 1;
+        }
+        assert!(count < 10);
+    }
+
+    #[test]
+    fn blackjack() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = Blackjack::default();
+        assert_eq!(env.state_space().len().unwrap(), 200);
+        for _ in 0..1_000 {
+            // The initial state belongs to the states-space.
+            let state = env.reset(&mut rng).get_state();
+            assert!(env.state_space().contains(&state));
+            // Hit on twenty or less, then stick.
+            let (mut reward, mut is_done) = (0., false);
+            while !is_done {
+                let action = match env.get_state().0 < 20 {
+                    false => BlackjackAction::Stick,
+                    true => BlackjackAction::Hit,
+                };
+                (reward, _, is_done) = env.call_mut(&action, &mut rng);
+            }
+            assert!([-1., 0., 1.].contains(&reward));
+        }
+        // A natural wins, unless the dealer has a natural as well, which requires showing an ace or a ten-card.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let (mut count, mut draws) = (0, 0);
+        for _ in 0..50_000 {
+            let (player, showing, usable_ace) = env.reset(&mut rng).get_state();
+            if player == 21 && usable_ace && (2..=9).contains(&showing) {
+                let (reward, _, _) = env.call_mut(&BlackjackAction::Stick, &mut rng);
+                assert!(reward >= 0.);
+                count += 1;
+                draws += (reward == 0.) as usize;
+            }
+        }
+        // Only a few initial twenty-ones are not naturals, i.e. three cards dealt while hitting below twelve.
+        assert!(count > 1_000 && (draws as f64) < 0.03 * count as f64);
+    }
+
+    #[test]
+    fn random_walk() {
+        // Initialize the env.
+        let env = RandomWalk::five_states();
+        // Evaluate the (only) policy, which is optimal.
+        let v = value_iteration(&env, 1., 1e-9);
+        for (s, v_s) in env.true_values().into_iter().enumerate() {
+            assert_relative_eq!(v[&s], v_s, epsilon = 1e-6);
+        }
+        assert_relative_eq!(v[&1], 1. / 6., epsilon = 1e-6);
+        assert_relative_eq!(RandomWalk::nineteen_states().true_values()[10], 0.);
+    }
+
+    #[test]
+    fn gamblers_problem() {
+        // Initialize the env.
+        let env = GamblersProblem::default();
+        // Stakes are positive, hence every action of a non-terminal state changes the capital.
+        assert_eq!(env.action_space().len(), Some(50));
+        for s in 1..env.goal() {
+            for a in env.action_space().iter().unwrap() {
+                assert!(env.transitions(&s, &a).into_iter().all(|(_, _, next, _)| next != s));
+            }
+        }
+        // Compute the optimal value function.
+        let v = value_iteration(&env, 1., 1e-9);
+        // With p_h < 0.5, the optimal probability of winning from half of the goal is p_h.
+        assert_relative_eq!(v[&50], 0.4, epsilon = 1e-6);
+        assert_relative_eq!(v[&25], 0.16, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn jacks_car_rental() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = JacksCarRental::new(10);
+        assert_eq!(env.state_space().len().unwrap(), 441);
+        assert_eq!(env.action_space().len().unwrap(), 11);
+        // The transition probabilities sum to one.
+        for s in [(0, 0), (10, 10), (20, 3), (20, 20)] {
+            for a in env.action_space().iter().unwrap() {
+                let p: f64 = env.transitions(&s, &a).into_iter().map(|(p, ..)| p).sum();
+                assert_relative_eq!(p, 1., epsilon = 1e-9);
+            }
+        }
+        // Moving cars has a cost.
+        let expected = |a| {
+            env.transitions(&(10, 10), &a)
+                .into_iter()
+                .map(|(p, r, ..)| p * r)
+                .sum::<f64>()
+        };
+        assert!(expected(0) > expected(1));
+        // Episodes are truncated by the time horizon.
+        env.reset(&mut rng);
+        assert!((0..10).all(|i| env.call_mut(&0, &mut rng).2 == (i == 9)));
+    }
+
+    #[test]
+    fn discretizer() {
+        let d = Discretizer::new(vec![0., -1.], vec![1., 1.], vec![4, 2]);
+        assert_eq!(d.space().len().unwrap(), 8);
+        assert_eq!(d.call(&[0., -1.]), vec![0, 0]);
+        assert_eq!(d.call(&[0.3, 0.5]), vec![1, 1]);
+        // Values outside the bounds are clamped.
+        assert_eq!(d.call(&[-5., 5.]), vec![0, 1]);
+        assert_eq!(d.call(&[1., 1.]), vec![3, 1]);
+    }
+
+    #[test]
+    fn mountain_car() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = MountainCar::default();
+        assert_eq!(env.state_space().len().unwrap(), 400);
+        // Always pushing right does not reach the goal.
+        env.reset(&mut rng);
+        let mut count = 1;
+        while !env.call_mut(&2, &mut rng).2 {
+            count += 1;
+        }
+        assert_eq!(count, 200);
+        // Pushing along the velocity reaches the goal.
+        env.reset(&mut rng);
+        let mut count = 1;
+        while !env.call_mut(&if env.observation()[1] < 0. { 0 } else { 2 }, &mut rng).2 {
+            count += 1;
+        }
+        assert!(count < 200);
+        assert!(env.observation()[0] >= MountainCar::GOAL);
+    }
+
+    #[test]
+    fn pendulum() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = Pendulum::new(10);
+        assert_eq!(env.action_space().len(), None);
+        let state = env.reset(&mut rng).get_state();
+        assert!(env.state_space().contains(&state));
+        assert_relative_eq!(state[0].powi(2) + state[1].powi(2), 1., epsilon = 1e-12);
+        // Actions outside the action space are clipped.
+        let (mut clipped, mut bounded) = (env.clone(), env.clone());
+        assert_eq!(
+            clipped.call_mut(&vec![100.], &mut rng),
+            bounded.call_mut(&vec![Pendulum::MAX_TORQUE], &mut rng)
+        );
+        // Rewards are penalties and episodes are truncated by the time horizon.
+        for i in 0..10 {
+            let (reward, state, is_done) = env.call_mut(&vec![0.], &mut rng);
+            assert!(reward <= 0.);
+            assert!(env.state_space().contains(&state));
+            assert_eq!(is_done, i == 9);
+        }
+    }
+
+    #[test]
+    fn continuous() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = Continuous::new(CartPole::default());
+        // The continuous state space is neither finite nor enumerable.
+        assert_eq!(env.state_space().len(), None);
+        assert!(env.state_space().iter().is_none());
+        assert_eq!(env.action_space().len(), Some(2));
+        // The states are the observations of the underlying env.
+        let state = env.reset(&mut rng).get_state();
+        assert_eq!(state, env.inner().get_observation());
+        assert!(env.state_space().contains(&state));
+        let mut count = 1;
+        loop {
+            let (_, state, is_done) = env.call_mut(&(count % 2), &mut rng);
+            assert_eq!(state, env.inner().observation().to_vec());
+            assert!(env.state_space().contains(&state));
+            if is_done {
+                break;
+            }
+            count += 1;
+        }
+        assert!(count < 500);
+    }
+
+    #[test]
+    fn cart_pole() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = CartPole::default();
+        assert_eq!(env.state_space().len().unwrap(), 324);
+        // Always pushing left makes the pole fall.
+        env.reset(&mut rng);
+        let mut count = 1;
+        while !env.call_mut(&0, &mut rng).2 {
+            count += 1;
+        }
+        assert!(count < 50);
+        assert!(env.observation()[2].abs() > CartPole::THETA_THRESHOLD);
+        // Pushing against the pole angle keeps it balanced longer.
+        let mut env = CartPole::default().with_horizon(100);
+        env.reset(&mut rng);
+        let mut count = 1;
+        while !env.call_mut(&if env.observation()[3] < 0. { 0 } else { 1 }, &mut rng).2 {
+            count += 1;
+        }
+        assert_eq!(count, 100);
+    }
+
+    #[test]
+    fn acrobot() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env.
+        let mut env = Acrobot::default().with_horizon(100);
+        assert_eq!(env.state_space().len().unwrap(), 1296);
+        // Without torque the acrobot never swings up.
+        let state = env.reset(&mut rng).get_state();
+        assert!(env.state_space().contains(&state));
+        let mut count = 1;
+        loop {
+            let (reward, _, is_done) = env.call_mut(&1, &mut rng);
+            assert_eq!(reward, -1.);
+            if is_done {
+                break;
+            }
+            count += 1;
+        }
+        assert_eq!(count, 100);
+        // Angles are wrapped and velocities are bounded.
+        let [theta1, theta2, dtheta1, dtheta2] = env.observation();
+        assert!(theta1.abs() <= std::f64::consts::PI && theta2.abs() <= std::f64::consts::PI);
+        assert!(dtheta1.abs() <= Acrobot::MAX_VEL_1 && dtheta2.abs() <= Acrobot::MAX_VEL_2);
+    }
+
+    #[test]
+    fn non_stationary_bandit() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Arm means perform random walks, which are undone by reset.
+        let mut env = NonStationaryBandit::testbed(10, 100);
+        env.reset(&mut rng);
+        let mut count = 1;
+        while !env.call_mut(&0, &mut rng).2 {
+            count += 1;
+        }
+        assert_eq!(count, 100);
+        assert!(env.means().iter().all(|&m| m != 0.));
+        assert!(env.reset(&mut rng).means().iter().all(|&m| m == 0.));
+        // Arm means switch at change-points.
+        let drift = Drift::Piecewise(vec![(2, vec![1., 0.]), (4, vec![0., 2.])]);
+        let mut env = NonStationaryBandit::new([0., 1.].into_iter(), drift, 10).with_noise(0.);
+        env.reset(&mut rng);
+        let rewards: Vec<_> = (0..6).map(|_| env.call_mut(&1, &mut rng).0).collect();
+        assert_eq!(rewards, [1., 1., 0., 0., 2., 2.]);
+        // Arm means oscillate periodically.
+        let mut env = NonStationaryBandit::new([0., 0.].into_iter(), Drift::Periodic(1., 4.), 10).with_noise(0.);
+        env.reset(&mut rng);
+        assert_relative_eq!(env.means()[0], 0.);
+        assert_relative_eq!(env.means()[1], 0., epsilon = 1e-12);
+        env.call_mut(&0, &mut rng);
+        assert_relative_eq!(env.means()[0], 1.);
+        assert_relative_eq!(env.means()[1], -1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_stationary_bandit_negative_deviation_should_panic() {
+        NonStationaryBandit::new([0., 0.].into_iter(), Drift::RandomWalk(-0.01), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_stationary_bandit_zero_period_should_panic() {
+        NonStationaryBandit::new([0., 0.].into_iter(), Drift::Periodic(1., 0.), 10);
+    }
+
+    #[test]
+    fn non_stationary_bandit_oracle() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // The oracle tracks the current arm means.
+        let drift = Drift::Piecewise(vec![(1, vec![0., 2.])]);
+        let mut env = NonStationaryBandit::new([1., 0.].into_iter(), drift, 10);
+        env.reset(&mut rng);
+        assert_eq!(env.oracle().unwrap().optimal_action(&()), 0);
+        env.call_mut(&0, &mut rng);
+        assert_eq!(env.oracle().unwrap().optimal_action(&()), 1);
+        assert_eq!(env.oracle().unwrap().regret(&0, &()), 2.);
     }
 }
@@ -0,0 +1,66 @@
+mod features {
+    use approx::*;
+    use reilly::{
+        features::{Features, Fourier, Polynomial, RadialBasis, TileCoding},
+        spaces::Bounded,
+    };
+
+    #[test]
+    pub fn tile_coding() {
+        let tc = TileCoding::new(Bounded::new(vec![0., -1.], vec![1., 1.]), vec![4, 4], 8, 4096);
+        assert_eq!(tc.len(), 4096);
+        // One tile is active for each tiling.
+        let x = tc.call(&vec![0.3, 0.2]);
+        assert_eq!(x.iter().sum::<f64>(), 8.);
+        assert!(x.iter().all(|&x| x == 0. || x == 1. || x == 2.));
+        // Close states share most tiles, far states share none.
+        let shared = |a: &[f64], b: &[f64]| {
+            let (a, b) = (tc.indices(a), tc.indices(b));
+            a.iter().filter(|i| b.contains(i)).count()
+        };
+        assert_eq!(shared(&[0.3, 0.2], &[0.3, 0.2]), 8);
+        assert!(shared(&[0.3, 0.2], &[0.31, 0.21]) >= 6);
+        assert_eq!(shared(&[0.3, 0.2], &[0.9, -0.8]), 0);
+        // States outside the space are clipped.
+        assert_eq!(tc.indices(&[2., 0.]), tc.indices(&[1., 0.]));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn tile_coding_should_panic() {
+        TileCoding::new(Bounded::new(vec![0.], vec![f64::INFINITY]), vec![4], 8, 4096);
+    }
+
+    #[test]
+    pub fn radial_basis() {
+        let rbf = RadialBasis::new(Bounded::new(vec![0., 0.], vec![2., 4.]), vec![3, 2], 0.25);
+        assert_eq!(rbf.len(), 6);
+        // The feature of a center is one.
+        let x = rbf.call(&vec![1., 4.]);
+        assert_relative_eq!(x[3], 1.);
+        assert_relative_eq!(x[1], (-0.25_f64 / (2. * 0.0625)).exp());
+        assert!(x.iter().all(|&x| x > 0. && x <= 1.));
+    }
+
+    #[test]
+    pub fn polynomial() {
+        let p = Polynomial::new(Bounded::new(vec![0., 0.], vec![2., 1.]), 2);
+        assert_eq!(p.len(), 9);
+        // The features are the products of the powers of the normalized dimensions.
+        let x = p.call(&vec![1., 0.5]);
+        assert_relative_eq!(x[0], 1.);
+        assert_relative_eq!(x[4], 0.25);
+        assert_relative_eq!(x[8], 0.0625);
+    }
+
+    #[test]
+    pub fn fourier() {
+        let f = Fourier::new(Bounded::new(vec![-1.], vec![1.]), 3);
+        assert_eq!(f.len(), 4);
+        // The features are the cosines of the normalized dimensions.
+        let x = f.call(&vec![0.]);
+        for (x, y) in x.into_iter().zip([1., 0., -1., 0.]) {
+            assert_relative_eq!(x, y, epsilon = 1e-12);
+        }
+    }
+}
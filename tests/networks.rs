@@ -0,0 +1,98 @@
+mod networks {
+    use approx::*;
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::networks::{Activation, Adam, Loss, Mlp, Optimizer, Sgd};
+
+    #[test]
+    pub fn activation() {
+        assert_eq!(Activation::ReLU.call(-1.), 0.);
+        assert_eq!(Activation::ReLU.derivative(Activation::ReLU.call(2.)), 1.);
+        // The derivative of the hyperbolic tangent is given by its output.
+        let y = Activation::Tanh.call(0.5);
+        assert_relative_eq!(Activation::Tanh.derivative(y), 1. / 0.5_f64.cosh().powi(2));
+    }
+
+    #[test]
+    pub fn loss() {
+        // The Huber loss is squared for small errors and linear for large ones.
+        assert_relative_eq!(Loss::Huber(1.).call(0.5, 0.), Loss::Squared.call(0.5, 0.));
+        assert_relative_eq!(Loss::Huber(1.).call(3., 0.), 2.5);
+        assert_relative_eq!(Loss::Huber(1.).derivative(-3., 0.), -1.);
+        assert_relative_eq!(Loss::Squared.derivative(-3., 0.), -3.);
+    }
+
+    #[test]
+    pub fn mlp() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        for activation in [Activation::Identity, Activation::ReLU, Activation::Tanh] {
+            let mut network = Mlp::new(vec![3, 5, 4, 2], activation, &mut rng);
+            assert_eq!(network.parameters().len(), 3 * 5 + 5 + 5 * 4 + 4 + 4 * 2 + 2);
+            let x: Vec<f64> = (0..3).map(|_| rng.gen_range(-1. ..1.)).collect();
+            // The gradient of the outputs weighted by dy matches its finite differences.
+            let dy = [0.3, -0.7];
+            let f = |network: &Mlp| network.call(&x).iter().zip(dy).map(|(y, dy)| y * dy).sum::<f64>();
+            let grad = network.gradient(&x, &dy);
+            for (i, g) in grad.iter().enumerate() {
+                let mut step = vec![0.; grad.len()];
+                step[i] = 1e-6;
+                let mut plus = network.clone();
+                plus.update(&step);
+                assert_relative_eq!((f(&plus) - f(&network)) / 1e-6, g, epsilon = 1e-4);
+            }
+            // Resetting the network restores its initial parameters.
+            let theta = network.parameters().to_vec();
+            network.update(&grad);
+            network.reset();
+            assert_eq!(network.parameters(), theta);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn mlp_should_panic() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        Mlp::new(vec![3], Activation::ReLU, &mut rng);
+    }
+
+    // Fits a network to a nonlinear function with the given optimizer, returning the final loss.
+    fn fit<O>(mut optimizer: O) -> f64
+    where
+        O: Optimizer,
+    {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        let mut network = Mlp::new(vec![1, 16, 1], Activation::Tanh, &mut rng);
+        let xs: Vec<f64> = (0..20).map(|i| i as f64 / 10. - 1.).collect();
+        let loss = |network: &Mlp| {
+            xs.iter()
+                .map(|x| Loss::Squared.call(network.call(&[*x])[0], x.powi(2)))
+                .sum::<f64>()
+                / xs.len() as f64
+        };
+        for _ in 0..500 {
+            let mut grad = vec![0.; network.parameters().len()];
+            for x in &xs {
+                let dy = Loss::Squared.derivative(network.call(&[*x])[0], x.powi(2)) / xs.len() as f64;
+                for (g, h) in grad.iter_mut().zip(network.gradient(&[*x], &[dy])) {
+                    *g += h;
+                }
+            }
+            network.update(&optimizer.step(&grad));
+        }
+
+        loss(&network)
+    }
+
+    #[test]
+    pub fn optimizers() {
+        // Both optimizers fit the parabola, far below its variance.
+        assert!(fit(Sgd::new(0.1).with_momentum(0.9)) < 1e-3);
+        assert!(fit(Adam::new(1e-2)) < 1e-3);
+    }
+}
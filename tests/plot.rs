@@ -0,0 +1,125 @@
+#![cfg(feature = "plot")]
+
+mod plot {
+    use std::fs::{read_to_string, remove_file};
+
+    use rand::SeedableRng;
+    use rand_distr::Normal;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::{
+        agents::{
+            bandits::{arms::Bernoulli, Arms, MultiArmedBandit},
+            Agent,
+        },
+        envs::{Env, FarWest},
+        plot::Figure,
+        policies::EpsilonGreedy,
+        sessions::{Benchmark, NoProgress, OnlineSession},
+        Error,
+    };
+
+    // Initializes a far-west environment with oracle.
+    fn far_west() -> FarWest<Normal<f64>> {
+        let env = [Normal::new(0., 1.), Normal::new(1., 1.), Normal::new(2., 1.)]
+            .into_iter()
+            .map(|d| d.unwrap());
+
+        FarWest::new(env, 49).with_oracle()
+    }
+
+    // Registers a system font to render the text of the figures, returning whether it is available.
+    #[cfg(feature = "plot-png")]
+    fn register_font() -> bool {
+        match std::fs::read("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf") {
+            Ok(font) => reilly::plot::register_font(Box::leak(font.into_boxed_slice())).is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    #[test]
+    fn figures() {
+        #[cfg(feature = "plot-png")]
+        if !register_font() {
+            return;
+        }
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Compare epsilon-greedy MABs with different epsilons.
+        let mab = |epsilon| {
+            move || {
+                let env = far_west();
+                let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+                MultiArmedBandit::new(EpsilonGreedy::new(epsilon), Arms::from_actions_arms_iter(mab))
+            }
+        };
+        let data = Benchmark::new()
+            .with_agent("epsilon-0.1", mab(0.1))
+            .with_agent("epsilon-0.5", mab(0.5))
+            .with_environment("far-west", far_west)
+            .call(&OnlineSession::new(1, 5).with_progress(NoProgress), &mut rng);
+        // Plot the figures of the benchmark, with one line for each agent.
+        for (name, figure) in [
+            ("reward", Figure::learning_curve("step")),
+            ("regret", Figure::cumulative_regret("step")),
+            ("optimal", Figure::optimal_actions("step")),
+        ] {
+            let path = std::env::temp_dir().join(format!("reilly-{}-{}.svg", name, std::process::id()));
+            figure
+                .with_series("agent")
+                .with_title(name)
+                .call(&data, &path, &mut rng)
+                .unwrap();
+            let svg = read_to_string(&path).unwrap();
+            assert!(svg.starts_with("<svg"));
+            assert!(svg.contains("epsilon-0.1") && svg.contains("epsilon-0.5"));
+            assert!(svg.contains("<polyline") && svg.contains("<polygon"));
+            remove_file(&path).unwrap();
+        }
+        // Unsupported formats and missing columns are rejected.
+        let path = std::env::temp_dir().join(format!("reilly-{}.svg", std::process::id()));
+        assert!(matches!(
+            Figure::learning_curve("step").call(&data, std::env::temp_dir().join("reilly.gif"), &mut rng),
+            Err(Error::Plot(_))
+        ));
+        assert!(matches!(
+            Figure::learning_curve("missing").call(&data, &path, &mut rng),
+            Err(Error::DataFrame(_))
+        ));
+        assert!(matches!(
+            Figure::learning_curve("step")
+                .with_series("missing")
+                .call(&data, &path, &mut rng),
+            Err(Error::DataFrame(_))
+        ));
+        let data = data.drop("regret").unwrap();
+        assert!(matches!(
+            Figure::cumulative_regret("step").call(&data, &path, &mut rng),
+            Err(Error::DataFrame(_))
+        ));
+        assert!(!path.exists());
+    }
+
+    #[cfg(feature = "plot-png")]
+    #[test]
+    fn figures_png() {
+        use reilly::sessions::Session;
+
+        if !register_font() {
+            return;
+        }
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env and the MAB.
+        let mut env = far_west();
+        let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+        let mut mab = MultiArmedBandit::new(EpsilonGreedy::new(0.1), Arms::from_actions_arms_iter(mab));
+        let data = OnlineSession::new(1, 3)
+            .with_progress(NoProgress)
+            .call(&mut mab, &mut env, &mut rng);
+        // Plot the learning curve to PNG.
+        let path = std::env::temp_dir().join(format!("reilly-reward-{}.png", std::process::id()));
+        Figure::learning_curve("step").call(&data, &path, &mut rng).unwrap();
+        assert_eq!(&std::fs::read(&path).unwrap()[1..4], b"PNG");
+        remove_file(&path).unwrap();
+    }
+}
@@ -6,8 +6,10 @@ mod policies {
     use rand_xoshiro::Xoshiro256PlusPlus;
     use reilly::{
         agents::bandits::{arms::Bernoulli, Arms},
-        policies::{Greedy, Policy, Random},
+        policies::{EpsilonGreedy, Gaussian, Greedy, ParametricPolicy, Policy, Random},
+        spaces::{Bounded, Space},
         values::StateActionValue,
+        Error,
     };
 
     #[test]
@@ -69,7 +71,7 @@ mod policies {
 
             let size = 100_000;
             let mut count: HashMap<i32, usize> = Default::default();
-            let relative_frequency = 1. / v.actions_iter().len() as f64;
+            let relative_frequency = 1. / v.action_space().len().unwrap() as f64;
 
             for _ in 0..size {
                 let a = pi.call(&v, &(), &mut rng);
@@ -98,4 +100,121 @@ mod policies {
 
         pi.call(&v, &(), &mut rng);
     }
+
+    #[test]
+    pub fn try_call() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        let v = Arms::<usize, f64, Bernoulli>::from_actions_arms_iter([].into_iter());
+        // Choosing from an empty action space fails.
+        assert!(matches!(
+            Greedy::default().try_call(&v, &(), &mut rng),
+            Err(Error::EmptyActionSpace)
+        ));
+        assert!(matches!(
+            Random::default().try_call(&v, &(), &mut rng),
+            Err(Error::EmptyActionSpace)
+        ));
+        assert!(matches!(
+            EpsilonGreedy::default().try_call(&v, &(), &mut rng),
+            Err(Error::EmptyActionSpace)
+        ));
+        // Epsilon must be a probability.
+        assert!(EpsilonGreedy::try_new(0.5).is_ok());
+        assert!(matches!(EpsilonGreedy::try_new(1.5), Err(Error::InvalidParameter(_))));
+    }
+
+    #[test]
+    pub fn epsilon_greedy() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        // The greedy action is the first one.
+        let v = Arms::from_actions_arms_iter([(0, Bernoulli::new(3., 1.)), (1, Bernoulli::new(1., 3.))].into_iter());
+        for epsilon in [0., 0.1, 0.5, 1.] {
+            let pi = EpsilonGreedy::new(epsilon);
+            // Explore with probability epsilon, where exploring selects the other action half of the times.
+            let size = 100_000;
+            let count = (0..size).filter(|_| pi.call(&v, &(), &mut rng) == 1).count();
+            assert_relative_eq!(count as f64 / size as f64, epsilon / 2., epsilon = 0.01);
+        }
+    }
+
+    #[test]
+    pub fn epsilon_greedy_decay() {
+        // Epsilon is linearly decayed to its final value, then held.
+        let mut pi = EpsilonGreedy::new(1.).with_decay(0.2, 4);
+        pi.step();
+        assert_relative_eq!(pi.epsilon(), 0.8);
+        (0..10).for_each(|_| pi.step());
+        assert_relative_eq!(pi.epsilon(), 0.2);
+        // Resetting the policy restarts the schedule.
+        pi.reset();
+        assert_relative_eq!(pi.epsilon(), 1.);
+        // Without a schedule, epsilon is constant.
+        let mut pi = EpsilonGreedy::new(0.1);
+        pi.step();
+        assert_relative_eq!(pi.epsilon(), 0.1);
+    }
+
+    #[test]
+    pub fn gaussian() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        let (actions, states) = (
+            Bounded::new(vec![-1.], vec![3.]),
+            Bounded::new(vec![-1., -1.], vec![1., 1.]),
+        );
+        let mut pi = Gaussian::new(actions.clone(), states).with_std(0.5);
+        // Parameters are the weights of the mean with bias and the log-standard deviation.
+        assert_eq!(pi.parameters().len(), 4);
+        pi.update(&[0.5, -1., 1., 0.]);
+        let state = vec![0.4, 0.2];
+        assert_relative_eq!(pi.mode(&state)[0], 1., epsilon = 1e-12);
+        // Samples have the given mean and standard deviation.
+        let size = 100_000;
+        let samples: Vec<f64> = (0..size).map(|_| pi.call(&state, &mut rng)[0]).collect();
+        let mean = samples.iter().sum::<f64>() / size as f64;
+        let std = (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / size as f64).sqrt();
+        assert_relative_eq!(mean, 1., epsilon = 0.01);
+        assert_relative_eq!(std, 0.5, epsilon = 0.01);
+        // The gradient of the log-probability matches its finite differences.
+        let log_prob = |pi: &Gaussian, a: f64| {
+            let (mu, sigma) = (pi.mode(&state)[0], pi.std()[0]);
+            -((a - mu) / sigma).powi(2) / 2. - sigma.ln()
+        };
+        let grad = pi.grad_log_prob(&vec![1.7], &state);
+        for (i, g) in grad.into_iter().enumerate() {
+            let h = 1e-6;
+            let mut step = vec![0.; 4];
+            step[i] = h;
+            let (mut plus, mut minus) = (pi.clone(), pi.clone());
+            plus.update(&step);
+            step[i] = -h;
+            minus.update(&step);
+            assert_relative_eq!(
+                g,
+                (log_prob(&plus, 1.7) - log_prob(&minus, 1.7)) / (2. * h),
+                epsilon = 1e-6
+            );
+        }
+        // Squashed samples are in the bounds of the action space.
+        let pi = pi.with_squashing(true);
+        assert_relative_eq!(pi.mode(&state)[0], 1. + 2. * 1_f64.tanh(), epsilon = 1e-12);
+        assert!((0..1_000).all(|_| actions.contains(&pi.call(&state, &mut rng))));
+        // Resetting restores zero mean and the initial standard deviation.
+        let mut pi = pi.with_squashing(false);
+        pi.reset();
+        assert_eq!(pi.mode(&state), vec![0.]);
+        assert_relative_eq!(pi.std()[0], 0.5, epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn gaussian_should_panic() {
+        let states = Bounded::new(vec![0.], vec![1.]);
+        Gaussian::new(Bounded::new(vec![0.], vec![f64::INFINITY]), states).with_squashing(true);
+    }
 }
@@ -0,0 +1,113 @@
+mod replays {
+    use approx::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::{
+        replays::{NStep, Prioritized, Replay, Uniform},
+        types::Transition,
+    };
+
+    // Constructs the transition from state i to state i + 1 with reward i.
+    fn transition(i: usize, is_done: bool) -> Transition<usize, f64, usize> {
+        Transition {
+            state: i,
+            action: 0,
+            reward: i as f64,
+            next_state: i + 1,
+            is_done,
+        }
+    }
+
+    #[test]
+    pub fn uniform() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        let mut replay = Uniform::new(3);
+        assert!(replay.is_empty());
+        (0..5).for_each(|i| replay.push(transition(i, false)));
+        // The oldest transitions are overwritten once the memory is full.
+        assert_eq!((replay.len(), replay.capacity()), (3, 3));
+        let mut states: Vec<_> = (0..3).map(|i| replay.get(i).unwrap().state).collect();
+        states.sort();
+        assert_eq!(states, [2, 3, 4]);
+        // Transitions are sampled with unit weights from the stored ones.
+        let batch = replay.sample(100, &mut rng);
+        assert_eq!(batch.len(), 100);
+        assert!(batch.iter().all(|(i, t, w)| replay.get(*i) == Some(t) && *w == 1.));
+        // The same seed gives the same batch.
+        let indices = |replay: &Uniform<_, _, _>| {
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+            replay
+                .sample(10, &mut rng)
+                .into_iter()
+                .map(|(i, _, _)| i)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(indices(&replay), indices(&replay));
+        replay.clear();
+        assert!(replay.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn uniform_should_panic() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        Uniform::<usize, f64, usize>::new(3).sample(1, &mut rng);
+    }
+
+    #[test]
+    pub fn prioritized() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        let mut replay = Prioritized::new(5).with_alpha(1.).with_beta(1.);
+        (0..4).for_each(|i| replay.push(transition(i, false)));
+        // Transitions are sampled proportionally to their priorities.
+        replay.update(0, 3.);
+        replay.update(1, 1.);
+        replay.update(2, 0.);
+        replay.update(3, 0.);
+        let batch = replay.sample(10_000, &mut rng);
+        let count = |j| batch.iter().filter(|(i, _, _)| *i == j).count() as f64 / batch.len() as f64;
+        assert_relative_eq!(count(0), 0.75, epsilon = 0.02);
+        assert_relative_eq!(count(1), 0.25, epsilon = 0.02);
+        // Weights are inversely proportional to the probabilities, relative to the maximum.
+        for (i, _, w) in &batch {
+            assert_relative_eq!(*w, [1. / 3., 1.][*i], epsilon = 1e-5);
+        }
+        // New transitions are given the maximum priority.
+        replay.push(transition(4, false));
+        let batch = replay.sample(10_000, &mut rng);
+        assert!(batch.iter().filter(|(i, _, _)| *i == 4).count() > 3_000);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn prioritized_should_panic() {
+        Prioritized::<usize, f64, usize>::new(3).update(0, 1.);
+    }
+
+    #[test]
+    pub fn n_step() {
+        let mut n_step = NStep::new(3, 0.5);
+        assert_relative_eq!(n_step.discount(), 0.125);
+        // Transitions are aggregated once n of them are pending ...
+        assert!(n_step.push(transition(1, false)).is_empty());
+        assert!(n_step.push(transition(2, false)).is_empty());
+        let aggregated = n_step.push(transition(3, false));
+        assert_eq!(aggregated.len(), 1);
+        assert_eq!((aggregated[0].state, aggregated[0].next_state), (1, 4));
+        assert_relative_eq!(aggregated[0].reward, 1. + 0.5 * 2. + 0.25 * 3.);
+        // ... while the remaining ones are aggregated at the end of the episode.
+        let aggregated = n_step.push(transition(4, true));
+        assert_eq!(aggregated.len(), 3);
+        assert_eq!(aggregated.iter().map(|t| t.state).collect::<Vec<_>>(), [2, 3, 4]);
+        assert!(aggregated.iter().all(|t| t.next_state == 5 && t.is_done));
+        assert_relative_eq!(aggregated[2].reward, 4.);
+        // The next episode starts from scratch.
+        assert!(n_step.push(transition(0, false)).is_empty());
+    }
+}
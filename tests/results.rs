@@ -0,0 +1,297 @@
+mod results {
+    use std::fs::{read_to_string, remove_file, File};
+
+    use approx::*;
+    use polars::prelude::*;
+    use rand::SeedableRng;
+    use rand_distr::Normal;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::{
+        agents::{
+            bandits::{arms::Bernoulli, Arms, MultiArmedBandit},
+            Agent,
+        },
+        envs::{Env, FarWest},
+        policies::EpsilonGreedy,
+        results::{
+            bonferroni, export, holm, mann_whitney, metadata_path, paired_bootstrap, welch, Comparison, Correction,
+            Format, Interval, Metadata, Summary, Test,
+        },
+        sessions::{NoProgress, OnlineSession, Session},
+        Error,
+    };
+
+    // Initializes the results of four repetitions of two episodes.
+    fn data() -> DataFrame {
+        let reps = Series::new("reps", [0u64, 0, 1, 1, 2, 2, 3, 3]);
+        let episode = Series::new("episode", [0u64, 1, 0, 1, 0, 1, 0, 1]);
+        let reward = Series::new("reward", [1., 10., 2., 20., 3., 30., 4., 40.]);
+
+        DataFrame::new(vec![reps, episode, reward]).unwrap()
+    }
+
+    #[test]
+    fn summary() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Summarize the rewards of each episode across repetitions.
+        let summary = Summary::new(["episode"], ["reward"]).call(&data(), &mut rng);
+        assert_eq!(
+            summary.get_column_names(),
+            [
+                "episode",
+                "reward_count",
+                "reward_mean",
+                "reward_std",
+                "reward_sem",
+                "reward_median",
+                "reward_q25",
+                "reward_q75",
+                "reward_lower",
+                "reward_upper"
+            ]
+        );
+        assert_eq!(summary.height(), 2);
+        let column = |name: &str| -> Vec<f64> {
+            summary
+                .column(name)
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_no_null_iter()
+                .collect()
+        };
+        assert_eq!(column("reward_count"), [4., 4.]);
+        assert_eq!(column("reward_mean"), [2.5, 25.]);
+        assert_relative_eq!(column("reward_std")[0], 1.2909944, epsilon = 1e-6);
+        assert_relative_eq!(column("reward_sem")[0], 0.6454972, epsilon = 1e-6);
+        assert_eq!(column("reward_median"), [2.5, 25.]);
+        assert_eq!(column("reward_q25"), [1.75, 17.5]);
+        assert_eq!(column("reward_q75"), [3.25, 32.5]);
+        // The t interval is centered on the mean, with the t quantile of 3 degrees of freedom.
+        assert_relative_eq!(column("reward_lower")[0], 2.5 - 3.1824463 * 0.6454972, epsilon = 1e-6);
+        assert_relative_eq!(column("reward_upper")[0], 2.5 + 3.1824463 * 0.6454972, epsilon = 1e-6);
+        // Summarize all the rows at once, with custom quantiles and bootstrap interval.
+        let summary = Summary::new(Vec::<String>::new(), ["reward", "episode"])
+            .with_quantiles([0., 0.07])
+            .with_interval(Interval::Bootstrap(0.9, 1000))
+            .call(&data(), &mut rng);
+        assert_eq!(summary.height(), 1);
+        assert_eq!(summary.width(), 2 * 9);
+        let column = |name: &str| summary.column(name).unwrap().f64().unwrap().get(0).unwrap();
+        assert_eq!(column("reward_q0"), 1.);
+        // Quantile names are rounded to two decimals of percent, despite floating point errors.
+        assert!(summary.column("reward_q7").is_ok());
+        assert_eq!(column("episode_mean"), 0.5);
+        assert!(column("reward_lower") < column("reward_mean"));
+        assert!(column("reward_mean") < column("reward_upper"));
+        assert!(1. <= column("reward_lower") && column("reward_upper") <= 40.);
+        // Summarizing missing columns fails.
+        let summary = Summary::new(["episode"], ["unknown"]).try_call(&data(), &mut rng);
+        assert!(matches!(summary, Err(Error::DataFrame(_))));
+    }
+
+    #[test]
+    fn summary_session() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env and the MAB.
+        let env = [Normal::new(0., 1.), Normal::new(1., 1.)]
+            .into_iter()
+            .map(|d| d.unwrap());
+        let mut env = FarWest::new(env, 9).with_oracle();
+        let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+        let mut mab = MultiArmedBandit::new(EpsilonGreedy::new(0.1), Arms::from_actions_arms_iter(mab));
+        // Summarize the per-step regret of an online session across repetitions.
+        let data = OnlineSession::new(1, 5)
+            .with_progress(NoProgress)
+            .call(&mut mab, &mut env, &mut rng);
+        let summary = Summary::new(["step"], ["regret", "optimal"]).call(&data, &mut rng);
+        assert_eq!(summary.height(), 10);
+        let count = summary.column("regret_count").unwrap().f64().unwrap();
+        assert!(count.into_no_null_iter().all(|c| c == 5.));
+        let optimal = summary.column("optimal_mean").unwrap().f64().unwrap();
+        assert!(optimal.into_no_null_iter().all(|o| (0. ..=1.).contains(&o)));
+    }
+
+    #[test]
+    fn significance() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        let (x, y) = ([1., 2., 3., 4., 5.], [2., 4., 6., 8., 10.]);
+        // Welch's t-test.
+        let test = welch(&x, &y);
+        assert_relative_eq!(test.statistic, -1.8973666, epsilon = 1e-6);
+        assert!(0.10 < test.p_value && test.p_value < 0.115);
+        assert_relative_eq!(test.effect, -1.2, epsilon = 1e-6);
+        // Mann-Whitney U test, with ties.
+        let test = mann_whitney(&x, &y);
+        assert_eq!(test.statistic, 5.);
+        assert_relative_eq!(test.p_value, 0.1413, epsilon = 1e-3);
+        assert_relative_eq!(test.effect, -0.6);
+        // Paired bootstrap test.
+        let z: Vec<_> = x.iter().enumerate().map(|(i, x)| x + 1. + 0.01 * i as f64).collect();
+        let test = paired_bootstrap(&z, &x, 999, &mut rng);
+        assert_relative_eq!(test.statistic, 1.02, epsilon = 1e-9);
+        assert_relative_eq!(test.p_value, 0.001);
+        assert_eq!(paired_bootstrap(&x, &x, 999, &mut rng).p_value, 1.);
+        // Multiple comparisons corrections.
+        let p = [0.01, 0.04, 0.03];
+        for (a, b) in bonferroni(&p).into_iter().zip([0.03, 0.12, 0.09]) {
+            assert_relative_eq!(a, b, epsilon = 1e-9);
+        }
+        for (a, b) in holm(&p).into_iter().zip([0.03, 0.06, 0.06]) {
+            assert_relative_eq!(a, b, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn comparison() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the results of three agents, for ten repetitions of two episodes.
+        let (mut agent, mut reps, mut reward) = (vec![], vec![], vec![]);
+        for (name, offset) in [("a", 10.), ("b", 10.), ("c", 0.)] {
+            for i in 0..10_u64 {
+                for j in 0..2 {
+                    agent.push(name);
+                    reps.push(i);
+                    reward.push(offset + (i % 3) as f64 + j as f64);
+                }
+            }
+        }
+        let data = DataFrame::new(vec![
+            Series::new("agent", agent),
+            Series::new("reps", reps),
+            Series::new("reward", reward),
+        ])
+        .unwrap();
+        // Compare the agents pairwise with each test.
+        for test in [Test::Welch, Test::MannWhitney, Test::PairedBootstrap(999)] {
+            let comparison = Comparison::new("agent", "reward")
+                .with_test(test)
+                .with_correction(Correction::Bonferroni)
+                .call(&data, &mut rng);
+            assert_eq!(
+                comparison.get_column_names(),
+                [
+                    "a",
+                    "b",
+                    "mean_a",
+                    "mean_b",
+                    "statistic",
+                    "effect",
+                    "p_value",
+                    "p_adjusted",
+                    "significant"
+                ]
+            );
+            let column = |name: &str| -> Vec<_> {
+                comparison
+                    .column(name)
+                    .unwrap()
+                    .utf8()
+                    .unwrap()
+                    .into_no_null_iter()
+                    .map(String::from)
+                    .collect()
+            };
+            assert_eq!(column("a"), ["a", "a", "b"]);
+            assert_eq!(column("b"), ["b", "c", "c"]);
+            let mean_a: Vec<_> = comparison
+                .column("mean_a")
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_no_null_iter()
+                .collect();
+            assert_eq!(mean_a, [11.4, 11.4, 11.4]);
+            let significant: Vec<_> = comparison
+                .column("significant")
+                .unwrap()
+                .bool()
+                .unwrap()
+                .into_no_null_iter()
+                .collect();
+            assert_eq!(significant, [false, true, true]);
+            let p_value = comparison.column("p_value").unwrap().f64().unwrap();
+            let p_adjusted = comparison.column("p_adjusted").unwrap().f64().unwrap();
+            for (p, q) in p_value.into_no_null_iter().zip(p_adjusted.into_no_null_iter()) {
+                assert_relative_eq!(q, (3. * p).min(1.));
+            }
+        }
+        // Each row can be an observation as well.
+        let comparison = Comparison::new("agent", "reward")
+            .with_unit(None::<String>)
+            .call(&data, &mut rng);
+        assert_eq!(comparison.height(), 3);
+        // Comparing missing columns fails.
+        let comparison = Comparison::new("unknown", "reward").try_call(&data, &mut rng);
+        assert!(matches!(comparison, Err(Error::DataFrame(_))));
+    }
+
+    #[test]
+    fn export_results() {
+        // Export the results with the run metadata.
+        let mut data = data();
+        let metadata = Metadata::new()
+            .with_seed(42)
+            .with_agent(&"agent \"quoted\"")
+            .with_entry("note", "line\nbreak");
+        let path = std::env::temp_dir().join(format!("reilly-export-{}.csv", std::process::id()));
+        export(&mut data, &path, Format::Csv, &metadata).unwrap();
+        // The results are read back unchanged.
+        let read = CsvReader::new(File::open(&path).unwrap())
+            .has_header(true)
+            .finish()
+            .unwrap();
+        assert_eq!(read.get_column_names(), data.get_column_names());
+        assert!(read
+            .column("reward")
+            .unwrap()
+            .series_equal(data.column("reward").unwrap()));
+        // The metadata are stored alongside.
+        let sidecar = metadata_path(&path);
+        assert_eq!(sidecar.extension().unwrap(), "json");
+        let json = read_to_string(&sidecar).unwrap();
+        for entry in [
+            "\"seed\": \"42\"",
+            "\"agent\": \"\\\"agent \\\\\\\"quoted\\\\\\\"\\\"\"",
+            "\"note\": \"line\\nbreak\"",
+            "\"rows\": \"8\"",
+            "\"columns\": \"reps,episode,reward\"",
+            "\"format\": \"Csv\"",
+            concat!("\"version\": \"", env!("CARGO_PKG_VERSION"), "\""),
+        ] {
+            assert!(json.contains(entry), "{} not in {}", entry, json);
+        }
+        assert!(json.contains("\"created\"") && json.contains("\"exported\""));
+        remove_file(&path).unwrap();
+        remove_file(&sidecar).unwrap();
+        // Each row is a JSON object in JSON Lines.
+        #[cfg(feature = "json")]
+        {
+            let path = path.with_extension("jsonl");
+            export(&mut data, &path, Format::JsonLines, &metadata).unwrap();
+            let lines = read_to_string(&path).unwrap();
+            assert_eq!(lines.lines().count(), data.height());
+            assert!(lines.lines().all(|l| l.starts_with('{') && l.contains("\"reward\"")));
+            remove_file(&path).unwrap();
+            remove_file(metadata_path(&path)).unwrap();
+        }
+        // The results are read back unchanged from Parquet.
+        #[cfg(feature = "parquet")]
+        {
+            let path = path.with_extension("parquet");
+            export(&mut data, &path, Format::Parquet, &metadata).unwrap();
+            let read = ParquetReader::new(File::open(&path).unwrap()).finish().unwrap();
+            assert!(read.frame_equal(&data));
+            remove_file(&path).unwrap();
+            remove_file(metadata_path(&path)).unwrap();
+        }
+        // Exporting to a missing directory fails with the crate error.
+        let path = path.join("missing").join("out.csv");
+        let result: reilly::Result<()> = export(&mut data, path, Format::Csv, &metadata);
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+}
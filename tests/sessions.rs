@@ -0,0 +1,506 @@
+mod sessions {
+    use std::{
+        ops::ControlFlow,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+
+    use approx::*;
+    use polars::prelude::*;
+    use rand::SeedableRng;
+    use rand_distr::Normal;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::{
+        agents::{
+            bandits::{arms::Bernoulli, Arms, MultiArmedBandit},
+            Agent, Reinforce,
+        },
+        envs::{Env, FarWest, Pendulum},
+        policies::{EpsilonGreedy, Gaussian},
+        sessions::{
+            Benchmark, Callback, Evaluation, LogProgress, NoProgress, OnlineSession, Parameter, Parameters, Progress,
+            Repetition, Search, Session, Sweep, TrainTestSession,
+        },
+        spaces::Bounded,
+        types::Transition,
+        values::Average,
+        Error,
+    };
+
+    type Mab = MultiArmedBandit<usize, f64, (), EpsilonGreedy, Arms<usize, f64, Bernoulli>>;
+
+    // Initializes a far-west environment with oracle and an epsilon-greedy MAB.
+    fn far_west() -> (Mab, FarWest<Normal<f64>>) {
+        let env = [Normal::new(0., 1.), Normal::new(1., 1.), Normal::new(2., 1.)]
+            .into_iter()
+            .map(|d| d.unwrap());
+        let env = FarWest::new(env, 99).with_oracle();
+        let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+        let mab = MultiArmedBandit::new(EpsilonGreedy::new(0.10), Arms::from_actions_arms_iter(mab));
+
+        (mab, env)
+    }
+
+    #[test]
+    fn parallel_repetitions() {
+        let (mut mab, mut env) = far_west();
+        // Execute the same sessions with different number of threads and the same seed.
+        let data: Vec<_> = [1, 2, 4]
+            .into_iter()
+            .map(|threads| {
+                let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+                let train_test = TrainTestSession::new(10, 3, 8)
+                    .with_repetition(Repetition::Independent)
+                    .with_threads(threads);
+                let online = OnlineSession::new(2, 8)
+                    .with_repetition(Repetition::Independent)
+                    .with_threads(threads);
+                (
+                    train_test.call(&mut mab, &mut env, &mut rng),
+                    online.call(&mut mab, &mut env, &mut rng),
+                )
+            })
+            .collect();
+        // Results are bit-identical regardless of the number of threads.
+        for w in data.windows(2) {
+            assert!(w[0].0.frame_equal(&w[1].0));
+            assert!(w[0].1.frame_equal(&w[1].1));
+        }
+        // Parallel runs with the default number of threads are reproducible from the seed.
+        let online = OnlineSession::new(2, 8)
+            .with_progress(NoProgress)
+            .with_repetition(Repetition::Independent);
+        let mut run = |seed| online.call(&mut mab, &mut env, &mut Xoshiro256PlusPlus::seed_from_u64(seed));
+        assert!(run(7).frame_equal(&run(7)));
+        assert!(!run(7).frame_equal(&run(8)));
+        // Repetitions are independent.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let data = OnlineSession::new(1, 2)
+            .with_repetition(Repetition::Independent)
+            .call(&mut mab, &mut env, &mut rng);
+        let first = data.filter(&data.column("reps").unwrap().equal(0).unwrap()).unwrap();
+        let second = data.filter(&data.column("reps").unwrap().equal(1).unwrap()).unwrap();
+        assert!(!first
+            .column("reward")
+            .unwrap()
+            .series_equal(second.column("reward").unwrap()));
+    }
+
+    #[test]
+    fn repetitions() {
+        let (mut mab, mut env) = far_west();
+        let prototype = format!("{:?}", mab);
+        // Independent runs leave the given agent untouched.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let session = OnlineSession::new(1, 4).with_repetition(Repetition::Independent);
+        session.call(&mut mab, &mut env, &mut rng);
+        assert_eq!(format!("{:?}", mab), prototype);
+        // Continual learning, i.e. the default, trains the given agent across repetitions.
+        let session = TrainTestSession::new(1, 1, 4);
+        assert_eq!(Repetition::default(), Repetition::Continual);
+        let data = session.call(&mut mab.clone(), &mut env, &mut Xoshiro256PlusPlus::seed_from_u64(42));
+        assert!(data.frame_equal(&session.call(
+            &mut mab.clone(),
+            &mut env,
+            &mut Xoshiro256PlusPlus::seed_from_u64(42)
+        )));
+        session.call(&mut mab, &mut env, &mut rng);
+        assert_ne!(format!("{:?}", mab), prototype);
+    }
+
+    #[test]
+    fn evaluation() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env and a fully exploring MAB.
+        let (_, mut env) = far_west();
+        let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+        let mut mab = MultiArmedBandit::new(EpsilonGreedy::new(1.), Arms::from_actions_arms_iter(mab));
+        assert!(!mab.is_eval());
+        assert!(mab.set_eval(true).is_eval());
+        mab.set_eval(false);
+        // Execute the experiment session, testing both on-policy and greedily.
+        let session = TrainTestSession::new(1, 2, 3).with_evaluation(Evaluation::Both);
+        let data = session.call(&mut mab, &mut env, &mut rng);
+        assert_eq!(data.height(), 3 * 2 * 2);
+        let mode = data.column("mode").unwrap().utf8().unwrap();
+        let optimal = data.column("optimal").unwrap().f64().unwrap();
+        for (m, o) in mode.into_iter().zip(optimal) {
+            let (m, o) = (m.unwrap(), o.unwrap());
+            match m {
+                // On-policy actions explore ...
+                "on-policy" => assert!(0. < o && o < 1.),
+                // ... while greedy actions do not.
+                "greedy" => assert!(o == 0. || o == 1.),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn online_session() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env and the MAB.
+        let (mut mab, mut env) = far_west();
+        // Execute the experiment session.
+        let session = OnlineSession::new(2, 3);
+        let data = session.call(&mut mab, &mut env, &mut rng);
+        assert_eq!(
+            data.get_column_names(),
+            ["reps", "episode", "step", "action", "reward", "regret", "optimal"]
+        );
+        // Each step of each episode of each repetition is recorded.
+        assert_eq!(data.height(), 3 * 2 * 100);
+        let step = data.column("step").unwrap().u64().unwrap();
+        assert_eq!(step.into_iter().flatten().max(), Some(2 * 100 - 1));
+        // The pseudo-regret is non-negative and zero only for optimal actions.
+        let regret = data.column("regret").unwrap().f64().unwrap();
+        let optimal = data.column("optimal").unwrap().bool().unwrap();
+        for (r, o) in regret.into_iter().zip(optimal) {
+            assert!(r.unwrap() >= 0.);
+            assert_eq!(r.unwrap() == 0., o.unwrap());
+        }
+    }
+
+    #[test]
+    fn try_call() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env and a MAB with a different action space.
+        let (_, mut env) = far_west();
+        let mab = (0..2).map(|a| (a, Bernoulli::default()));
+        let mut mab: Mab = MultiArmedBandit::new(EpsilonGreedy::new(0.10), Arms::from_actions_arms_iter(mab));
+        // Executing the experiment session fails.
+        let session = TrainTestSession::new(1, 1, 1).with_progress(NoProgress);
+        assert!(matches!(
+            session.try_call(&mut mab, &mut env, &mut rng),
+            Err(Error::SpaceMismatch("actions"))
+        ));
+        let session = OnlineSession::new(1, 1).with_progress(NoProgress);
+        assert!(matches!(
+            session.try_call(&mut mab, &mut env, &mut rng),
+            Err(Error::SpaceMismatch("actions"))
+        ));
+        // Executing the experiment session with an empty action space fails as well.
+        let mut mab: Mab = MultiArmedBandit::new(EpsilonGreedy::new(0.10), Arms::new([].into_iter()));
+        assert!(matches!(
+            session.try_call(&mut mab, &mut env, &mut rng),
+            Err(Error::EmptyActionSpace)
+        ));
+    }
+
+    // Counts the hooks invocations and stops after a given number of episodes of each phase.
+    #[derive(Default)]
+    struct Counter {
+        sessions: AtomicUsize,
+        repeats: AtomicUsize,
+        episodes: AtomicUsize,
+        steps: AtomicUsize,
+        rewards: AtomicUsize,
+        stop: Option<usize>,
+    }
+
+    impl Callback<usize, f64, (), Mab, FarWest<Normal<f64>>> for Counter {
+        fn on_session_end(&self) {
+            self.sessions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_repeat_end(&self, _repeat: usize, _agent: &Mab, _environment: &FarWest<Normal<f64>>) {
+            self.repeats.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_episode_end(
+            &self,
+            _repeat: usize,
+            episode: usize,
+            _agent: &Mab,
+            _environment: &FarWest<Normal<f64>>,
+        ) -> ControlFlow<()> {
+            self.episodes.fetch_add(1, Ordering::Relaxed);
+            match self.stop.is_some_and(|stop| episode + 1 >= stop) {
+                false => ControlFlow::Continue(()),
+                true => ControlFlow::Break(()),
+            }
+        }
+
+        fn on_step_start(
+            &self,
+            _repeat: usize,
+            _episode: usize,
+            _agent: &Mab,
+            _environment: &FarWest<Normal<f64>>,
+            _state: &(),
+        ) {
+            self.steps.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_step_end(
+            &self,
+            _repeat: usize,
+            _episode: usize,
+            _agent: &Mab,
+            _environment: &FarWest<Normal<f64>>,
+            transition: &Transition<usize, f64, ()>,
+        ) {
+            assert!(transition.action < 3);
+            self.rewards.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn callbacks() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env and the MAB.
+        let (mut mab, mut env) = far_west();
+        // Execute the experiment sessions, invoking the callbacks.
+        let (first, second) = (Counter::default(), Counter::default());
+        let session = TrainTestSession::new(4, 2, 3);
+        let data = session.call_with_callbacks(&mut mab, &mut env, &mut rng, &[&first, &second]);
+        assert_eq!(data.height(), 3 * 2);
+        for counter in [&first, &second] {
+            assert_eq!(counter.sessions.load(Ordering::Relaxed), 1);
+            assert_eq!(counter.repeats.load(Ordering::Relaxed), 3);
+            assert_eq!(counter.episodes.load(Ordering::Relaxed), 3 * (4 + 2));
+            assert_eq!(counter.steps.load(Ordering::Relaxed), 3 * (4 + 2) * 100);
+            assert_eq!(counter.rewards.load(Ordering::Relaxed), 3 * (4 + 2) * 100);
+        }
+        // Breaking at the end of an episode stops the remaining episodes of the phase.
+        let stop = Counter {
+            stop: Some(1),
+            ..Default::default()
+        };
+        let session = OnlineSession::new(5, 2);
+        let data = session.call_with_callbacks(&mut mab, &mut env, &mut rng, &[&stop]);
+        assert_eq!(data.height(), 2 * 100);
+        assert_eq!(stop.episodes.load(Ordering::Relaxed), 2);
+        let session = TrainTestSession::new(5, 5, 2);
+        let data = session.call_with_callbacks(&mut mab, &mut env, &mut rng, &[&stop]);
+        assert_eq!(data.height(), 2);
+    }
+
+    // Records the reported progress.
+    #[derive(Default)]
+    struct Record {
+        start: Vec<(usize, usize)>,
+        episodes: Vec<(usize, usize, f64)>,
+        repeats: usize,
+        finish: bool,
+    }
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Record>>);
+
+    impl Progress for Recorder {
+        fn start(&self, repeat: usize, episodes: usize) {
+            self.0.lock().unwrap().start.push((repeat, episodes));
+        }
+
+        fn episode(&self, repeat: usize, episode: usize, reward: f64) {
+            self.0.lock().unwrap().episodes.push((repeat, episode, reward));
+        }
+
+        fn repeat(&self, _repeat: usize) {
+            self.0.lock().unwrap().repeats += 1;
+        }
+
+        fn finish(&self) {
+            self.0.lock().unwrap().finish = true;
+        }
+    }
+
+    #[test]
+    fn progress() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Initialize the env and the MAB.
+        let (mut mab, mut env) = far_west();
+        // Report the progress of a train-test session.
+        let recorder = Recorder::default();
+        let session = TrainTestSession::new(4, 2, 3).with_progress(recorder.clone());
+        session.call(&mut mab, &mut env, &mut rng);
+        {
+            let record = recorder.0.lock().unwrap();
+            assert_eq!(record.start, [(3, 4)]);
+            assert_eq!(record.episodes.len(), 3 * 4);
+            assert!(record.episodes.iter().all(|&(i, j, _)| i < 3 && j < 4));
+            assert_eq!(record.repeats, 3);
+            assert!(record.finish);
+        }
+        // The cumulative reward of the online episodes is reported.
+        let recorder = Recorder::default();
+        let session = OnlineSession::new(2, 1).with_progress(recorder.clone());
+        let data = session.call(&mut mab, &mut env, &mut rng);
+        let reward: f64 = recorder.0.lock().unwrap().episodes.iter().map(|&(_, _, r)| r).sum();
+        assert_relative_eq!(
+            reward,
+            data.column("reward").unwrap().sum::<f64>().unwrap(),
+            epsilon = 1e-9
+        );
+        // Progress can be logged or silenced.
+        let session = OnlineSession::new(2, 2).with_progress(LogProgress::new(Duration::ZERO));
+        assert_eq!(session.call(&mut mab, &mut env, &mut rng).height(), 2 * 2 * 100);
+        let session = OnlineSession::new(2, 2).with_progress(NoProgress);
+        assert_eq!(session.call(&mut mab, &mut env, &mut rng).height(), 2 * 2 * 100);
+    }
+
+    #[test]
+    fn benchmark() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+        // Compare epsilon-greedy MABs with different epsilons on different environments.
+        let mab = |epsilon| {
+            move || {
+                let (_, env) = far_west();
+                let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+                MultiArmedBandit::new(EpsilonGreedy::new(epsilon), Arms::from_actions_arms_iter(mab))
+            }
+        };
+        let benchmark = Benchmark::new()
+            .with_agent("epsilon-0.1", mab(0.1))
+            .with_agent("epsilon-0.5", mab(0.5))
+            .with_agent("epsilon-0.1-copy", mab(0.1))
+            .with_environment("far-west", || far_west().1)
+            .with_environment("far-west-noisy", || {
+                let env = [Normal::new(0., 5.), Normal::new(1., 5.), Normal::new(2., 5.)]
+                    .into_iter()
+                    .map(|d| d.unwrap());
+                FarWest::new(env, 99).with_oracle()
+            });
+        let session = OnlineSession::new(1, 2).with_progress(NoProgress);
+        let data = benchmark.call(&session, &mut rng);
+        assert_eq!(
+            data.get_column_names(),
+            ["env", "agent", "reps", "episode", "step", "action", "reward", "regret", "optimal"]
+        );
+        assert_eq!(data.height(), 2 * 3 * 2 * 100);
+        // Combinations share the same seed, hence identical agents obtain identical results.
+        let select = |env: &str, agent: &str| {
+            let mask =
+                data.column("env").unwrap().equal(env).unwrap() & data.column("agent").unwrap().equal(agent).unwrap();
+            data.filter(&mask).unwrap().drop("agent").unwrap()
+        };
+        assert!(select("far-west", "epsilon-0.1").frame_equal(&select("far-west", "epsilon-0.1-copy")));
+        assert!(!select("far-west", "epsilon-0.1").frame_equal(&select("far-west", "epsilon-0.5")));
+    }
+
+    #[test]
+    fn sweep() {
+        // Initialize the env and the MAB factories.
+        let mab = |parameters: &Parameters| {
+            let (_, env) = far_west();
+            let mab = env.action_space().iter().unwrap().map(|a| (a, Bernoulli::default()));
+            MultiArmedBandit::new(
+                EpsilonGreedy::new(parameters["epsilon"]),
+                Arms::from_actions_arms_iter(mab),
+            )
+        };
+        let session = TrainTestSession::new(5, 2, 2).with_progress(NoProgress);
+        // Search over a grid of epsilons.
+        let sweep = Sweep::new(mab, || far_west().1)
+            .with_parameter("epsilon", Parameter::Values(vec![0., 0.1, 0.5, 1.]))
+            .with_seeds(3);
+        let data = sweep.call(&session, &mut Xoshiro256PlusPlus::seed_from_u64(42));
+        assert_eq!(
+            &data.get_column_names()[..5],
+            ["rank", "config", "epsilon", "score_count", "score_mean"]
+        );
+        assert_eq!(data.height(), 4);
+        let rank: Vec<_> = data
+            .column("rank")
+            .unwrap()
+            .u64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(rank, [1, 2, 3, 4]);
+        let score: Vec<_> = data
+            .column("score_mean")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert!(score.windows(2).all(|w| w[0] >= w[1]));
+        assert!(data
+            .column("score_count")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .all(|c| c == 3.));
+        // Parallel runs obtain the same results.
+        let parallel = sweep
+            .with_threads(0)
+            .call(&session, &mut Xoshiro256PlusPlus::seed_from_u64(42));
+        assert!(data.frame_equal(&parallel));
+        // Search over randomly sampled configurations, minimizing the regret.
+        let sweep = Sweep::new(mab, || far_west().1)
+            .with_parameter("epsilon", Parameter::LogUniform(0.01, 0.5))
+            .with_parameter("unused", Parameter::Uniform(-1., 1.))
+            .with_search(Search::Random(5))
+            .with_metric("regret", false);
+        let data = sweep.call(&session, &mut Xoshiro256PlusPlus::seed_from_u64(42));
+        assert_eq!(data.height(), 5);
+        let epsilon = data.column("epsilon").unwrap().f64().unwrap();
+        assert!(epsilon.into_no_null_iter().all(|e| (0.01..0.5).contains(&e)));
+        let score: Vec<_> = data
+            .column("score_mean")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert!(score.windows(2).all(|w| w[0] <= w[1]));
+        // Invalid hyperparameters and missing metrics fail with the crate error.
+        let rng = &mut Xoshiro256PlusPlus::seed_from_u64(42);
+        let empty = Sweep::new(mab, || far_west().1)
+            .with_parameter("epsilon", Parameter::Values(vec![]))
+            .with_search(Search::Random(2));
+        assert!(matches!(empty.try_call(&session, rng), Err(Error::InvalidParameter(_))));
+        let infinite = Sweep::new(mab, || far_west().1).with_parameter("epsilon", Parameter::Uniform(0., 1.));
+        assert!(matches!(
+            infinite.try_call(&session, rng),
+            Err(Error::InvalidParameter(_))
+        ));
+        let missing = Sweep::new(mab, || far_west().1)
+            .with_parameter("epsilon", Parameter::Values(vec![0.1]))
+            .with_metric("missing", true);
+        assert!(matches!(missing.try_call(&session, rng), Err(Error::DataFrame(_))));
+    }
+
+    #[test]
+    fn continuous_control() {
+        // Initialize the random number generator.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        // Initialize a squashed-Gaussian REINFORCE agent on the pendulum environment.
+        let mut env = Pendulum::new(50);
+        let pi = Gaussian::new(
+            Bounded::new(vec![-Pendulum::MAX_TORQUE], vec![Pendulum::MAX_TORQUE]),
+            Bounded::new(vec![-1., -1., -Pendulum::MAX_SPEED], vec![1., 1., Pendulum::MAX_SPEED]),
+        )
+        .with_squashing(true);
+        let mut agent = Reinforce::new(pi, Average::new().with_step_size(0.1)).with_step_size(1e-4);
+        // Sessions accept continuous actions and states.
+        let data = TrainTestSession::new(10, 3, 2)
+            .with_progress(NoProgress)
+            .call(&mut agent, &mut env, &mut rng);
+        assert_eq!(data.height(), 3 * 2);
+        let reward = data.column("reward").unwrap().f64().unwrap();
+        assert!(reward.into_no_null_iter().all(|r| r.is_finite() && r <= 0.));
+        // Mismatching action spaces are rejected.
+        let pi = Gaussian::new(
+            Bounded::new(vec![-1.], vec![1.]),
+            Bounded::new(vec![-1., -1., -Pendulum::MAX_SPEED], vec![1., 1., Pendulum::MAX_SPEED]),
+        );
+        let mut agent = Reinforce::new(pi, Average::new());
+        assert!(matches!(
+            OnlineSession::new(1, 1).try_call(&mut agent, &mut env, &mut rng),
+            Err(Error::SpaceMismatch("actions"))
+        ));
+    }
+}
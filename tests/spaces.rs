@@ -0,0 +1,103 @@
+mod spaces {
+    use std::collections::HashSet;
+
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use reilly::spaces::{Bounded, Discrete, Enumerated, MultiDiscrete, Space};
+
+    #[test]
+    pub fn discrete() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        let space = Discrete(3);
+        assert_eq!(space.len(), Some(3));
+        assert!(space.contains(&2) && !space.contains(&3));
+        assert_eq!(space.iter().unwrap().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert!((0..100).all(|_| space.contains(&space.sample(&mut rng))));
+        assert!(Discrete(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn discrete_should_panic() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        Discrete(0).sample(&mut rng);
+    }
+
+    #[test]
+    pub fn bounded() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        let space = Bounded::new(vec![-1., 0., f64::NEG_INFINITY], vec![1., f64::INFINITY, f64::INFINITY]);
+        // Continuous spaces are neither finite nor enumerable.
+        assert_eq!(space.len(), None);
+        assert!(space.iter().is_none());
+        assert!(space.contains(&vec![0.5, 10., -1e9]));
+        assert!(!space.contains(&vec![1.5, 0., 0.]) && !space.contains(&vec![0., 0.]));
+        assert!((0..100).all(|_| space.contains(&space.sample(&mut rng))));
+        // Elements are clipped to the bounds.
+        assert_eq!(space.clip(&[2., -1., 3.]), vec![1., 0., 3.]);
+        // Spaces are equal if they have the same bounds.
+        assert!(space.eq_dyn(&space.clone()));
+        assert!(!space.eq_dyn(&Bounded::new(vec![-1., 0., 0.], vec![1., 1., 1.])));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn bounded_should_panic() {
+        Bounded::new(vec![1.], vec![0.]);
+    }
+
+    #[test]
+    pub fn multi_discrete() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        let space = MultiDiscrete(vec![2, 3]);
+        assert_eq!(space.len(), Some(6));
+        assert!(space.contains(&vec![1, 2]) && !space.contains(&vec![2, 0]));
+        // Elements are enumerated in lexicographic order.
+        let elements: Vec<_> = space.iter().unwrap().collect();
+        assert_eq!(elements[..4], [vec![0, 0], vec![0, 1], vec![0, 2], vec![1, 0]]);
+        assert_eq!(elements.len(), 6);
+        assert!((0..100).all(|_| space.contains(&space.sample(&mut rng))));
+    }
+
+    #[test]
+    pub fn enumerated() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        // Duplicates are discarded, keeping the insertion order.
+        let mut space = Enumerated::new(['b', 'a', 'b']);
+        assert!(space.push('c') && !space.push('a'));
+        assert_eq!(space.elements(), ['b', 'a', 'c']);
+        assert_eq!(space.len(), Some(3));
+        assert!((0..100).all(|_| space.contains(&space.sample(&mut rng))));
+    }
+
+    #[test]
+    pub fn product() {
+        // Initialize the random number generator.
+        let mut rng: Xoshiro256PlusPlus = SeedableRng::from_entropy();
+
+        let space = (Discrete(2), Enumerated::new(['a', 'b', 'c']));
+        assert_eq!(space.len(), Some(6));
+        assert!(space.contains(&(1, 'c')) && !space.contains(&(2, 'a')));
+        let elements: HashSet<_> = space.iter().unwrap().collect();
+        assert_eq!(elements.len(), 6);
+        assert!((0..100).all(|_| space.contains(&space.sample(&mut rng))));
+        // The product with a continuous space is not enumerable.
+        let space = (Discrete(2), Discrete(3), Bounded::new(vec![0.], vec![1.]));
+        assert_eq!(space.len(), None);
+        assert!(space.iter().is_none());
+        assert!(space.contains(&space.sample(&mut rng)));
+        // Products are equal if their components are.
+        assert!(space.eq_dyn(&space.clone()));
+        assert!(!space.eq_dyn(&(Discrete(2), Discrete(3), Bounded::new(vec![0.], vec![2.]))));
+    }
+}